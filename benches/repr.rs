@@ -0,0 +1,101 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::io::sink;
+use wrapbin::{
+    repr::{
+        array::ArrayFormatOptions, base64::Base64FormatOptions, dump::DumpFormatOptions, format,
+        write, write_io, BinaryFormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Benchmarks
+// ------------------------------------------------------------------------------------------------
+
+const LENGTHS: [usize; 3] = [256, 4096, 65536];
+
+fn value(length: usize) -> Binary<'static> {
+    Binary::from((0..length).map(|i| (i * 7 + 3) as u8).collect::<Vec<u8>>())
+}
+
+///
+/// The array representation as it was built before streaming, a string per byte joined at the
+/// end, as a baseline for the streaming functions.
+///
+fn per_byte_strings(value: &Binary<'_>) -> String {
+    format!(
+        "0X[{}]",
+        value
+            .iter()
+            .map(|b| format!("{b:02X}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn bench_array(c: &mut Criterion) {
+    let mut group = c.benchmark_group("array");
+    for length in LENGTHS {
+        let value = value(length);
+        group.throughput(Throughput::Bytes(length as u64));
+        group.bench_with_input(
+            BenchmarkId::new("per_byte_strings", length),
+            &value,
+            |b, value| b.iter(|| per_byte_strings(black_box(value))),
+        );
+        bench_representation(&mut group, &value, ArrayFormatOptions::default());
+    }
+    group.finish();
+}
+
+fn bench_dump(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dump");
+    for length in LENGTHS {
+        let value = value(length);
+        group.throughput(Throughput::Bytes(length as u64));
+        bench_representation(
+            &mut group,
+            &value,
+            DumpFormatOptions::default().show_ascii_gutter(true),
+        );
+    }
+    group.finish();
+}
+
+fn bench_base64(c: &mut Criterion) {
+    let mut group = c.benchmark_group("base64");
+    for length in LENGTHS {
+        let value = value(length);
+        group.throughput(Throughput::Bytes(length as u64));
+        bench_representation(&mut group, &value, Base64FormatOptions::pem());
+    }
+    group.finish();
+}
+
+///
+/// Compare formatting into a new string with writing into a reused string, and to an I/O stream.
+///
+fn bench_representation<O: Into<BinaryFormatOptions>>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    value: &Binary<'_>,
+    options: O,
+) {
+    let options = options.into();
+    let length = value.len();
+    group.bench_with_input(BenchmarkId::new("format", length), value, |b, value| {
+        b.iter(|| format(black_box(value), options.clone()))
+    });
+    let mut buffer = String::new();
+    group.bench_with_input(BenchmarkId::new("write", length), value, |b, value| {
+        b.iter(|| {
+            buffer.clear();
+            write(&mut buffer, black_box(value), options.clone()).unwrap();
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("write_io", length), value, |b, value| {
+        b.iter(|| write_io(&mut sink(), black_box(value), options.clone()).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_array, bench_dump, bench_base64);
+criterion_main!(benches);
@@ -1,9 +1,40 @@
 /*!
 Provides this crate's [`Error`] and [`Result`] types as well as helper functions.
 
+The functions that parse a representation return a [`ParseError`], which locates the [`Error`]
+in the parsed string with the offset, line and column of the offending text along with what was
+expected in its place.
+
+*/
+#![cfg_attr(not(feature = "repr-array"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-array", doc = "```rust")]
+/*!
+use wrapbin::{error::Error, repr::array::parse_array_representation};
+
+let error = parse_array_representation("0x[01, 0e\n b2, 8c").unwrap_err();
+
+assert_eq!(error.kind(), &Error::InvalidArrayBrackets);
+assert_eq!((error.offset(), error.line(), error.column()), (17, 2, 8));
+assert_eq!(error.found(), "");
+assert_eq!(error.expected(), Some("`]`"));
+```
+
+With the `miette` feature errors are also diagnostics, so that a command-line tool may show the
+offending text in place, given the parsed string as the source code of the report.
+
+```rust,ignore
+let report = miette::Report::new(error).with_source_code(input.to_string());
+eprintln!("{report:?}");
+```
+
  */
 
-use alloc::{format, string::ToString};
+#[cfg(feature = "miette")]
+use alloc::boxed::Box;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 use core::{
     cmp::PartialEq,
     convert::From,
@@ -35,12 +66,45 @@ pub enum Error {
     InvalidArrayBrackets,
     /// A string representing a `u8` byte value could not be parsed.
     InvalidByteRepresentation { source: ParseIntError },
+    /// An encoded representation, such as base64, is invalid.
+    InvalidEncoding,
+    /// The length of a value, or an encoded representation, is not a multiple of the required group size.
+    InvalidLength { length: usize, multiple_of: usize },
+    /// The checksum included in an encoded representation does not match the decoded value.
+    ChecksumMismatch,
+    /// A record in a record-based representation, such as Intel HEX or S-record, is invalid.
+    InvalidRecord,
+    /// The checksum of a record in a record-based representation does not match its contents.
+    RecordChecksumMismatch,
+    /// A line index in a dump representation does not match the offset of the line's first byte.
+    InvalidLineOffset { expected: usize, found: String },
+    /// The address of a byte is past the highest address, `limit`, a representation can hold.
+    AddressOutOfRange { address: u64, limit: u64 },
+    /// The records of a record-based representation leave more than `limit` bytes of gaps
+    /// between them, which would be filled when parsing.
+    AddressGapTooLarge { limit: usize },
+    /// A header is longer than the `limit` bytes the header record of a representation can hold.
+    HeaderTooLong { length: usize, limit: usize },
+}
+
+///
+/// An error found while parsing a representation, with the location of the offending text in the
+/// parsed string and a description of the token expected in its place.
+///
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    kind: Error,
+    offset: usize,
+    line: usize,
+    column: usize,
+    found: String,
+    expected: Option<&'static str>,
 }
 
 ///
 /// A `Result` type that specifically uses this crate's `Error`.
 ///
-pub type Result<T> = StdResult<Error, T>;
+pub type Result<T> = StdResult<T, Error>;
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -52,10 +116,163 @@ pub fn parse_error(source: ParseIntError) -> Error {
     Error::InvalidByteRepresentation { source }
 }
 
+///
+/// Construct an [`Error::InvalidEncoding`] located at `offset` in `input`.
+///
+#[cfg(any(
+    feature = "repr-ascii85",
+    feature = "repr-base32",
+    feature = "repr-base58",
+    feature = "repr-base64"
+))]
+#[inline]
+pub(crate) fn invalid_encoding(
+    input: &str,
+    offset: usize,
+    length: usize,
+    expected: &'static str,
+) -> ParseError {
+    Error::InvalidEncoding.at(input, offset, length, Some(expected))
+}
+
+///
+/// The byte offset of `part`, which must be a slice of `input`, from the start of `input`.
+///
+#[cfg(any(
+    feature = "repr-array",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-intel-hex",
+    feature = "repr-srec",
+    feature = "repr-string"
+))]
+#[inline]
+pub(crate) fn offset_in(input: &str, part: &str) -> usize {
+    (part.as_ptr() as usize).saturating_sub(input.as_ptr() as usize)
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Error {
+    ///
+    /// Locate this error at the byte `offset` in `input`, where `length` bytes are the offending
+    /// text and `expected` describes the token expected there.
+    ///
+    #[cfg(feature = "repr-any")]
+    pub(crate) fn at(
+        self,
+        input: &str,
+        offset: usize,
+        length: usize,
+        expected: Option<&'static str>,
+    ) -> ParseError {
+        ParseError::new(self, input, offset, length, expected)
+    }
+}
+
+impl ParseError {
+    #[cfg(feature = "repr-any")]
+    fn new(
+        kind: Error,
+        input: &str,
+        offset: usize,
+        length: usize,
+        expected: Option<&'static str>,
+    ) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let mut end = offset.saturating_add(length).min(input.len());
+        while !input.is_char_boundary(end) {
+            end += 1;
+        }
+        let (line, column) = Self::line_column(input, offset);
+        Self {
+            kind,
+            offset,
+            line,
+            column,
+            found: input[offset..end].to_string(),
+            expected,
+        }
+    }
+
+    #[cfg(feature = "repr-any")]
+    fn line_column(input: &str, offset: usize) -> (usize, usize) {
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or_default();
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    ///
+    /// Move the location of this error by `leading` bytes, when the string that was parsed is
+    /// `input` less `leading` bytes at its start, such as leading whitespace.
+    ///
+    #[cfg(feature = "repr-any")]
+    pub(crate) fn offset_by(mut self, input: &str, leading: usize) -> Self {
+        self.offset += leading;
+        (self.line, self.column) = Self::line_column(input, self.offset);
+        self
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> &Error {
+        &self.kind
+    }
+
+    /// As [`ParseError::kind`], but returns the error by value.
+    pub fn into_kind(self) -> Error {
+        self.kind
+    }
+
+    /// The byte offset of the offending text in the parsed string.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The line of the offending text, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column, in characters, of the offending text in its line, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The offending text, which is empty at the end of the parsed string.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// A description of the token expected in place of the offending text, if known.
+    pub fn expected(&self) -> Option<&'static str> {
+        self.expected
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} Found ", self.kind)?;
+        if self.found.is_empty() {
+            write!(f, "the end of input")?;
+        } else {
+            write!(f, "'{}'", self.found.escape_debug())?;
+        }
+        write!(f, " at line {}, column {}", self.line, self.column)?;
+        match self.expected {
+            Some(expected) => write!(f, "; expected {expected}."),
+            None => write!(f, "."),
+        }
+    }
+}
+
 impl Debug for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -64,10 +281,41 @@ impl Debug for Error {
             Self::InvalidRadixPrefix => write!(f, "InvalidRadixPrefix"),
             Self::InvalidStringQuotes => write!(f, "InvalidStringQuotes"),
             Self::InvalidArrayBrackets => write!(f, "InvalidArrayBrackets"),
+            Self::ChecksumMismatch => write!(f, "ChecksumMismatch"),
+            Self::InvalidEncoding => write!(f, "InvalidEncoding"),
+            Self::InvalidRecord => write!(f, "InvalidRecord"),
+            Self::RecordChecksumMismatch => write!(f, "RecordChecksumMismatch"),
             Self::InvalidByteRepresentation { source } => f
                 .debug_struct("InvalidByteRepresentation")
                 .field("source", source)
                 .finish(),
+            Self::InvalidLength {
+                length,
+                multiple_of,
+            } => f
+                .debug_struct("InvalidLength")
+                .field("length", length)
+                .field("multiple_of", multiple_of)
+                .finish(),
+            Self::InvalidLineOffset { expected, found } => f
+                .debug_struct("InvalidLineOffset")
+                .field("expected", expected)
+                .field("found", found)
+                .finish(),
+            Self::AddressOutOfRange { address, limit } => f
+                .debug_struct("AddressOutOfRange")
+                .field("address", address)
+                .field("limit", limit)
+                .finish(),
+            Self::AddressGapTooLarge { limit } => f
+                .debug_struct("AddressGapTooLarge")
+                .field("limit", limit)
+                .finish(),
+            Self::HeaderTooLong { length, limit } => f
+                .debug_struct("HeaderTooLong")
+                .field("length", length)
+                .field("limit", limit)
+                .finish(),
         }
     }
 }
@@ -90,7 +338,30 @@ impl Display for Error {
                 Self::InvalidArrayBrackets =>
                     "The binary array representation must be correctly enclosed in brackets: '[' and ']'.".to_string(),
                 Self::InvalidByteRepresentation { source } => {
-                    format!("Failed to parse individual byte representation; source error: {source}")
+                    format!("Failed to parse individual byte representation; source error: {source}.")
+                }
+                Self::ChecksumMismatch =>
+                    "The checksum in the encoded binary representation does not match the value.".to_string(),
+                Self::InvalidEncoding =>
+                    "The encoded binary representation is invalid.".to_string(),
+                Self::InvalidLength { length, multiple_of } => {
+                    format!("The length {length} is not a multiple of {multiple_of}.")
+                }
+                Self::InvalidRecord =>
+                    "The record is invalid.".to_string(),
+                Self::RecordChecksumMismatch =>
+                    "The checksum of the record does not match its contents.".to_string(),
+                Self::InvalidLineOffset { expected, found } => {
+                    format!("The dump line index '{found}' does not match the expected offset {expected}.")
+                }
+                Self::AddressOutOfRange { address, limit } => {
+                    format!("The address {address:#X} is past the highest address that can be represented, {limit:#X}.")
+                }
+                Self::AddressGapTooLarge { limit } => {
+                    format!("The gaps between records are more than {limit} bytes in total.")
+                }
+                Self::HeaderTooLong { length, limit } => {
+                    format!("The header of {length} bytes is longer than the {limit} bytes a record can hold.")
                 }
             }
         )
@@ -106,6 +377,12 @@ impl StdError for Error {
     }
 }
 
+impl StdError for ParseError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.kind.source()
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations ❱ From<*>
 // ------------------------------------------------------------------------------------------------
@@ -115,3 +392,56 @@ impl From<ParseIntError> for Error {
         Self::InvalidByteRepresentation { source }
     }
 }
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        error.kind
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Diagnostic
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for Error {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let code = match self {
+            Self::InvalidRepresentation => "wrapbin::invalid_representation",
+            Self::MissingRadixPrefix => "wrapbin::missing_radix_prefix",
+            Self::InvalidRadixPrefix => "wrapbin::invalid_radix_prefix",
+            Self::InvalidStringQuotes => "wrapbin::invalid_string_quotes",
+            Self::InvalidArrayBrackets => "wrapbin::invalid_array_brackets",
+            Self::InvalidByteRepresentation { .. } => "wrapbin::invalid_byte_representation",
+            Self::InvalidEncoding => "wrapbin::invalid_encoding",
+            Self::InvalidLength { .. } => "wrapbin::invalid_length",
+            Self::ChecksumMismatch => "wrapbin::checksum_mismatch",
+            Self::InvalidRecord => "wrapbin::invalid_record",
+            Self::RecordChecksumMismatch => "wrapbin::record_checksum_mismatch",
+            Self::InvalidLineOffset { .. } => "wrapbin::invalid_line_offset",
+            Self::AddressOutOfRange { .. } => "wrapbin::address_out_of_range",
+            Self::AddressGapTooLarge { .. } => "wrapbin::address_gap_too_large",
+            Self::HeaderTooLong { .. } => "wrapbin::header_too_long",
+        };
+        Some(Box::new(code))
+    }
+}
+
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for ParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.kind.code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let label = match self.expected {
+            Some(expected) => format!("expected {expected}"),
+            None => "here".to_string(),
+        };
+        Some(Box::new(core::iter::once(miette::LabeledSpan::new(
+            Some(label),
+            self.offset,
+            self.found.len(),
+        ))))
+    }
+}
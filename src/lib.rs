@@ -16,10 +16,21 @@
 //! - **fmt**; Adds support for the format specifiers in the `std::fmt` module:
 //!   `Binary`, `LowerHex`, `Octal`, and `UpperHex`. This **requires** the
 //!   *repr-array* feature. **Default**.
+//! - **miette**; Implements `miette::Diagnostic` for errors, so that parse errors
+//!   may be reported as annotated snippets of the input. This **requires** the
+//!   *std* feature.
 //! - Representation formats:
 //!   - **repr-array**; Array representation; e.g. `0x[01, 0e, b2, 8c]`. **Default**.
+//!   - **repr-ascii85**; Ascii85 representation, including the btoa and Z85 variants.
+//!   - **repr-base32**; Base32 representation.
+//!   - **repr-base58**; Base58 representation.
+//!   - **repr-base58-check**; Adds the Base58Check checksum to the base58 representation.
 //!   - **repr-base64**; Base64 representation.
 //!   - **repr-dump**; Dump representation.
+//!   - **repr-escaped**; Escaped string representation; e.g. `b"GET /\r\n"`.
+//!   - **repr-intel-hex**; Intel HEX representation.
+//!   - **repr-source**; Source code declarations in Rust, C, Python, Go, or JavaScript.
+//!   - **repr-srec**; Motorola S-record representation.
 //!   - **repr-string**; String representation; e.g. `0x"01_0e_b2_8c"`.
 //!   - **repr-color**; Adds color to the representations above.
 //!
@@ -177,22 +188,16 @@
 
 #[cfg(any(test, feature = "alloc", not(feature = "std")))]
 extern crate alloc;
-/*
- * The following is no longer supported as there is no known external crate named
- * `std` after approximately edition 2018. However, it does make a nice logical
- * follow-on from the line above for the `alloc` crate.
- *
- *  ```
- * #[cfg(any(test, feature = "std"))]
- * extern crate std;
- * ```
- */
+#[cfg(feature = "std")]
+extern crate std;
 
 use alloc::{
     borrow::{Borrow, Cow},
     string::String,
     vec::Vec,
 };
+#[cfg(feature = "fmt")]
+use core::fmt::{Formatter, Result as FmtResult};
 use core::{
     convert::{AsRef, From},
     default::Default,
@@ -200,11 +205,6 @@ use core::{
     ops::Deref,
     option::Option,
 };
-#[cfg(feature = "fmt")]
-use core::{
-    fmt::{Formatter, Result as FmtResult},
-    write,
-};
 
 // ------------------------------------------------------------------------------------------------
 // Public Type ❱ Binary
@@ -474,20 +474,17 @@ impl Deref for Binary<'_> {
 // ------------------------------------------------------------------------------------------------
 
 #[cfg(feature = "fmt")]
-use crate::repr::array::{array_representation, ArrayFormatOptions};
+use crate::repr::array::{write_array_representation, ArrayFormatOptions};
 
 #[cfg(feature = "fmt")]
 impl core::fmt::Display for Binary<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
+        write_array_representation(
             f,
-            "{}",
-            array_representation(
-                self,
-                &ArrayFormatOptions::default()
-                    .with_decimal_bytes()
-                    .compact(f.alternate())
-            )
+            self,
+            &ArrayFormatOptions::default()
+                .with_decimal_bytes()
+                .compact(f.alternate()),
         )
     }
 }
@@ -495,15 +492,12 @@ impl core::fmt::Display for Binary<'_> {
 #[cfg(feature = "fmt")]
 impl core::fmt::Binary for Binary<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(
+        write_array_representation(
             f,
-            "{}",
-            array_representation(
-                self,
-                &ArrayFormatOptions::default()
-                    .with_binary_bytes()
-                    .compact(f.alternate())
-            )
+            self,
+            &ArrayFormatOptions::default()
+                .with_binary_bytes()
+                .compact(f.alternate()),
         )
     }
 }
@@ -511,15 +505,12 @@ impl core::fmt::Binary for Binary<'_> {
 #[cfg(feature = "fmt")]
 impl core::fmt::Octal for Binary<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(
+        write_array_representation(
             f,
-            "{}",
-            array_representation(
-                self,
-                &ArrayFormatOptions::default()
-                    .with_octal_bytes()
-                    .compact(f.alternate())
-            )
+            self,
+            &ArrayFormatOptions::default()
+                .with_octal_bytes()
+                .compact(f.alternate()),
         )
     }
 }
@@ -527,15 +518,12 @@ impl core::fmt::Octal for Binary<'_> {
 #[cfg(feature = "fmt")]
 impl core::fmt::LowerHex for Binary<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(
+        write_array_representation(
             f,
-            "{}",
-            array_representation(
-                self,
-                &ArrayFormatOptions::default()
-                    .with_lower_hex_bytes()
-                    .compact(f.alternate())
-            )
+            self,
+            &ArrayFormatOptions::default()
+                .with_lower_hex_bytes()
+                .compact(f.alternate()),
         )
     }
 }
@@ -543,19 +531,67 @@ impl core::fmt::LowerHex for Binary<'_> {
 #[cfg(feature = "fmt")]
 impl core::fmt::UpperHex for Binary<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(
+        write_array_representation(
             f,
-            "{}",
-            array_representation(
-                self,
-                &ArrayFormatOptions::default()
-                    .with_upper_hex_bytes()
-                    .compact(f.alternate())
-            ),
+            self,
+            &ArrayFormatOptions::default()
+                .with_upper_hex_bytes()
+                .compact(f.alternate()),
         )
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Parse
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "repr-any")]
+impl core::str::FromStr for Binary<'static> {
+    type Err = crate::error::ParseError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        crate::repr::parse(s).map(|binary| Binary::from(binary.into_owned()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Display Representation
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "repr-any")]
+impl Binary<'_> {
+    ///
+    /// Display the value in the representation selected by `options`, which is written as it is
+    /// formatted rather than built as a string first, for use in `format!` and similar macros.
+    /// The width, fill and alignment of the format are honored for single-line representations.
+    ///
+    /// ```rust
+    /// use wrapbin::{Binary, repr::array::ArrayFormatOptions};
+    ///
+    /// let binary = Binary::from(b"ok");
+    /// let options = ArrayFormatOptions::default().with_lower_hex_bytes();
+    ///
+    /// assert_eq!(format!("{}", binary.display(options.clone())), "0x[6f, 6b]");
+    /// assert_eq!(format!("{:*>14}", binary.display(options)), "****0x[6f, 6b]");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Formatting the result with `format!` or `to_string` panics if the value cannot be written
+    /// in the representation with `options`, other writers receive a `fmt::Error` instead; see
+    /// [`try_format`](crate::repr::try_format) to return an error.
+    ///
+    pub fn display<O>(&self, options: O) -> impl core::fmt::Display + '_
+    where
+        O: Into<crate::repr::BinaryFormatOptions>,
+    {
+        crate::repr::DisplayRepresentation {
+            value: Binary::from(self.as_slice()),
+            options: options.into(),
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementation ❱ Binary
 // ------------------------------------------------------------------------------------------------
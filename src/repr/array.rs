@@ -116,22 +116,23 @@
 //! ```
 //!
 
+#[cfg(feature = "repr-color")]
+use crate::repr::color::ColorPolicy;
 use crate::{
-    error::Error,
-    repr::{BinaryFormatOptions, ByteKind, RadixFormat, ReprComponentKind},
+    error::{offset_in, Error, ParseError},
+    repr::{
+        color::Theme, format_with, split_radix_prefix, BinaryFormatOptions, Markup, RadixFormat,
+        ReprComponentKind,
+    },
     Binary,
 };
-use alloc::{
-    format,
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::{string::String, vec::Vec};
 use core::{
     clone::Clone,
     cmp::{Eq, PartialEq},
     convert::{AsRef, From},
     default::Default,
-    fmt::Debug,
+    fmt::{self, Debug, Write},
     iter::Iterator,
     marker::Copy,
     option::Option::Some,
@@ -146,7 +147,8 @@ use core::{
 pub struct ArrayFormatOptions {
     radix_format: RadixFormat,
     compact: bool,
-    colored: bool,
+    theme: Theme,
+    markup: Markup,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -154,71 +156,66 @@ pub struct ArrayFormatOptions {
 // ------------------------------------------------------------------------------------------------
 
 pub fn array_representation(value: &Binary<'_>, options: &ArrayFormatOptions) -> String {
-    let prefix = if options.colored {
-        let style = ReprComponentKind::Prefix.display_style(true);
-        format!("{style}{}{style:#}", options.radix_format.prefix_str(),)
-    } else {
-        options.radix_format.prefix_str().to_string()
-    };
-    let (left_paren, right_paren) = if options.colored {
-        let style = ReprComponentKind::Delimiter.display_style(true);
-        (format!("{style}[{style:#}"), format!("{style}]{style:#}"))
-    } else {
-        ("[".to_string(), "]".to_string())
-    };
-    let comma = if options.colored {
-        let style = ReprComponentKind::Separator.display_style(true);
-        format!(
-            "{style},{style:#}{}",
-            if options.compact { "" } else { " " }
-        )
-    } else {
-        if options.compact { "," } else { ", " }.to_string()
-    };
-    format!(
-        "{prefix}{left_paren}{}{right_paren}",
-        value
-            .as_ref()
-            .iter()
-            .map(|b| {
-                if options.colored {
-                    let style = ByteKind::ascii_char_display_style(b, true);
-                    format!(
-                        "{style}{}{style:#}",
-                        options.radix_format.format(b, options.compact)
-                    )
-                } else {
-                    options.radix_format.format(b, options.compact).to_string()
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(&comma)
+    format_with(
+        value.len() * (options.radix_format.max_width() + 2) + 4,
+        |buffer| write_array_representation(buffer, value, options),
     )
 }
 
-pub fn parse_array_representation(s: &str) -> Result<Binary<'_>, Error> {
-    if !s.starts_with('0') {
-        return Err(Error::MissingRadixPrefix);
-    }
-    let s = &s[1..];
-    if !s.starts_with(['b', 'd', 'o', 'x', 'X']) {
-        return Err(Error::InvalidRadixPrefix);
-    }
-    let radix_char = s.chars().next().unwrap();
-    let s = &s[1..];
-    if !(s.starts_with('[') && s.ends_with(']')) {
-        return Err(Error::InvalidArrayBrackets);
+///
+/// Write the array representation of `value` to `w`, see [`array_representation`].
+///
+pub fn write_array_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &ArrayFormatOptions,
+) -> fmt::Result {
+    let markup = options.markup.resolve();
+    let prefix = options
+        .theme
+        .display_style(ReprComponentKind::Prefix, markup);
+    let delimiter = options
+        .theme
+        .display_style(ReprComponentKind::Delimiter, markup);
+    let separator = options
+        .theme
+        .display_style(ReprComponentKind::Separator, markup);
+    write!(
+        w,
+        "{prefix}{}{prefix:#}{delimiter}[{delimiter:#}",
+        options.radix_format.prefix_str()
+    )?;
+    for (i, b) in value.as_ref().iter().enumerate() {
+        if i > 0 {
+            separator.write_start(w)?;
+            w.write_char(',')?;
+            separator.write_end(w)?;
+            if !options.compact {
+                w.write_char(' ')?;
+            }
+        }
+        let style = options.theme.byte_display_style(b, markup);
+        style.write_start(w)?;
+        options.radix_format.write(w, b, options.compact)?;
+        style.write_end(w)?;
     }
-    let s = &s[1..s.len() - 1];
-    if s.is_empty() {
+    write!(w, "{delimiter}]{delimiter:#}")
+}
+
+pub fn parse_array_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    let (byte_format, rest) = split_radix_prefix(s)?;
+    let Some(rest) = rest.strip_prefix('[') else {
+        return Err(Error::InvalidArrayBrackets.at(s, offset_in(s, rest), 1, Some("`[`")));
+    };
+    let Some(rest) = rest.strip_suffix(']') else {
+        return Err(Error::InvalidArrayBrackets.at(s, s.len(), 0, Some("`]`")));
+    };
+    if rest.is_empty() {
         Ok(Binary::from(Vec::new()))
     } else {
-        let byte_format = RadixFormat::from(Some(radix_char))?;
-        let radix = byte_format.radix();
-        let bytes = s.split(',');
         let mut result = Vec::new();
-        for byte in bytes {
-            result.push(u8::from_str_radix(byte.trim(), radix)?);
+        for byte in rest.split(',') {
+            result.push(byte_format.parse_byte(s, byte.trim())?);
         }
         Ok(Binary::from(result))
     }
@@ -269,11 +266,38 @@ impl ArrayFormatOptions {
         self
     }
 
-    /// Use color to denote byte kind according the ASCII conventions denoted by the
-    /// enums `ByteStyle` and `ReprStyle`.
+    /// Use color to denote the kind of each byte, a [`ByteKind`](crate::repr::ByteKind), and
+    /// each part of the representation, styled by the [`Theme`] set with [`Self::with_theme`].
     #[cfg(feature = "repr-color")]
     pub fn use_color(mut self, colored: bool) -> Self {
-        self.colored = colored;
+        self.markup = Markup::from_color(colored);
+        self
+    }
+
+    /// Use color according to `policy`, which is resolved, for [`ColorPolicy::Auto`] by
+    /// checking the environment and stream, each time the representation is written.
+    #[cfg(feature = "repr-color")]
+    pub fn with_color_policy(mut self, policy: ColorPolicy) -> Self {
+        self.markup = Markup::from_policy(policy);
+        self
+    }
+
+    /// Sets the theme used to style the representation when color is used.
+    #[cfg(feature = "repr-color")]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    #[cfg(any(feature = "repr-html", feature = "repr-markdown"))]
+    pub(crate) fn with_markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    #[cfg(all(feature = "std", feature = "repr-color"))]
+    pub(crate) fn for_terminal(mut self, is_terminal: bool) -> Self {
+        self.markup = self.markup.resolve_for(is_terminal);
         self
     }
 }
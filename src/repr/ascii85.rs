@@ -0,0 +1,387 @@
+//!
+//! An Ascii85 encoding of binary data, where each group of four bytes is encoded as five
+//! characters. Three variants are supported:
+//!
+//! 1. **Adobe**; as used in PostScript and PDF streams, the characters `!` to `u` with `z`
+//!    representing a group of four zero bytes, optionally enclosed in `<~` and `~>` delimiters.
+//! 2. **btoa**; as Adobe, without delimiters, and with `y` also representing a group of four space
+//!    characters.
+//! 3. **Z85**; the ZeroMQ variant, as used for CURVE keys, which uses a string-safe alphabet and
+//!    requires the value to be a multiple of four bytes.
+//!
+//! For the Adobe and btoa variants a final group of fewer than four bytes is encoded as one more
+//! character than the number of bytes. When parsing these variants any whitespace is ignored. A
+//! value that is not a multiple of four bytes cannot be represented in Z85, see
+//! [`try_ascii85_representation`].
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-ascii85"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-ascii85", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, format, ascii85::Ascii85FormatOptions}
+//! };
+//!
+//! let binary = Binary::from([
+//!     0x86_u8,0x4f_u8,0xd2_u8,0x6f_u8,0xb5_u8,0x59_u8,0xf7_u8,0x5b_u8,
+//! ]);
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Ascii85FormatOptions::adobe()),
+//!     "<~L/669[9<6.~>".to_string(),
+//! );
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Ascii85FormatOptions::z85()),
+//!     "HelloWorld".to_string(),
+//! );
+//! ```
+//!
+
+use crate::{
+    error::{invalid_encoding, Error, ParseError},
+    repr::{format_with, BinaryFormatOptions},
+    Binary,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    convert::TryFrom,
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ascii85FormatOptions {
+    variant: Ascii85Variant,
+    delimiters: bool,
+}
+
+///
+/// The variant of Ascii85 encoding to use.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ascii85Variant {
+    /// Adobe Ascii85, with `z` for groups of zero bytes and optional delimiters.
+    #[default]
+    Adobe,
+    /// The btoa encoding, with `z` for groups of zero bytes and `y` for groups of spaces.
+    Btoa,
+    /// ZeroMQ Z85, with a string-safe alphabet and no partial groups.
+    Z85,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Format `value` as Ascii85.
+///
+/// # Panics
+///
+/// If the Z85 variant is selected and the length of `value` is not a multiple of four, see
+/// [`try_ascii85_representation`] to return an error instead.
+///
+pub fn ascii85_representation(value: &Binary<'_>, options: &Ascii85FormatOptions) -> String {
+    format_with(value.len().div_ceil(4) * 5 + 4, |buffer| {
+        write_ascii85_representation(buffer, value, options)
+    })
+}
+
+///
+/// Write `value` as Ascii85 to `w`, see [`ascii85_representation`]. Nothing is written, and an
+/// error returned, if the Z85 variant is selected and the length of `value` is not a multiple of
+/// four.
+///
+pub fn write_ascii85_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &Ascii85FormatOptions,
+) -> fmt::Result {
+    options.check_length(value).map_err(|_| fmt::Error)?;
+    if options.has_delimiters() {
+        w.write_str(START_DELIMITER)?;
+    }
+    for group in value.chunks(4) {
+        let mut padded = [0_u8; 4];
+        padded[..group.len()].copy_from_slice(group);
+        let word = u32::from_be_bytes(padded);
+        if group.len() == 4 {
+            if let Some(shortcut) = options.variant.shortcut_for(word) {
+                w.write_char(shortcut)?;
+                continue;
+            }
+        }
+        let mut digits = [0_u8; 5];
+        let mut remainder = word;
+        for digit in digits.iter_mut().rev() {
+            *digit = (remainder % 85) as u8;
+            remainder /= 85;
+        }
+        for digit in &digits[..group.len() + 1] {
+            w.write_char(options.variant.symbol(*digit))?;
+        }
+    }
+    if options.has_delimiters() {
+        w.write_str(END_DELIMITER)?;
+    }
+    Ok(())
+}
+
+///
+/// Format `value` as Ascii85, returning an error if the Z85 variant is selected and the length
+/// of `value` is not a multiple of four.
+///
+pub fn try_ascii85_representation(
+    value: &Binary<'_>,
+    options: &Ascii85FormatOptions,
+) -> Result<String, Error> {
+    options.check_length(value)?;
+    Ok(ascii85_representation(value, options))
+}
+
+///
+/// Parse an Adobe Ascii85 representation, with or without delimiters.
+///
+pub fn parse_ascii85_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    parse_ascii85_representation_with(s, &Ascii85FormatOptions::default())
+}
+
+///
+/// Parse an Ascii85 representation using the variant in `options`. For the Adobe variant
+/// delimiters are required if enabled in `options`, and allowed otherwise.
+///
+pub fn parse_ascii85_representation_with<'a>(
+    s: &'a str,
+    options: &Ascii85FormatOptions,
+) -> Result<Binary<'a>, ParseError> {
+    let (start, content) = options.strip_delimiters(s)?;
+    if options.variant == Ascii85Variant::Z85 && !content.len().is_multiple_of(5) {
+        let remainder = content.len() % 5;
+        return Err(Error::InvalidLength {
+            length: content.len(),
+            multiple_of: 5,
+        }
+        .at(
+            s,
+            start + content.len() - remainder,
+            remainder,
+            Some("a complete group of 5 symbols"),
+        ));
+    }
+
+    let mut values = Vec::with_capacity(content.len() / 5 * 4 + 4);
+    let mut digits: Vec<(usize, u8)> = Vec::with_capacity(5);
+    for (offset, c) in content.char_indices() {
+        let offset = start + offset;
+        if c.is_ascii_whitespace() && options.variant != Ascii85Variant::Z85 {
+            continue;
+        }
+        if let Some(word) = options.variant.word_for_shortcut(c) {
+            if !digits.is_empty() {
+                return Err(invalid_encoding(s, offset, 1, EXPECTED_DIGIT));
+            }
+            values.extend_from_slice(&word.to_be_bytes());
+            continue;
+        }
+        let digit = options
+            .variant
+            .digit(c)
+            .ok_or_else(|| invalid_encoding(s, offset, c.len_utf8(), EXPECTED_DIGIT))?;
+        digits.push((offset, digit));
+        if digits.len() == 5 {
+            values.extend_from_slice(&decode_group(s, &digits)?);
+            digits.clear();
+        }
+    }
+    match digits.len() {
+        0 => {}
+        1 => {
+            return Err(invalid_encoding(
+                s,
+                digits[0].0,
+                1,
+                "at least 2 digits in the final group",
+            ))
+        }
+        length => {
+            // Pad with the highest digit, as the encoder truncated the group.
+            let last_offset = digits[length - 1].0;
+            digits.resize(5, (last_offset, 84));
+            values.extend_from_slice(&decode_group(s, &digits)?[..length - 1]);
+        }
+    }
+    Ok(Binary::from(values))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const START_DELIMITER: &str = "<~";
+const END_DELIMITER: &str = "~>";
+
+const Z85_SYMBOLS: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+const EXPECTED_DIGIT: &str = "an Ascii85 digit";
+
+///
+/// Decode a group of five digits, with their offsets in `s`, into a 32-bit word.
+///
+fn decode_group(s: &str, digits: &[(usize, u8)]) -> Result<[u8; 4], ParseError> {
+    let word = digits
+        .iter()
+        .fold(0_u64, |word, (_, digit)| word * 85 + u64::from(*digit));
+    u32::try_from(word).map(u32::to_be_bytes).map_err(|_| {
+        invalid_encoding(
+            s,
+            digits[digits.len() - 1].0,
+            1,
+            "a group that fits in 32 bits",
+        )
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Ascii85FormatOptions> for BinaryFormatOptions {
+    fn from(value: Ascii85FormatOptions) -> Self {
+        Self::Ascii85(value)
+    }
+}
+
+impl Ascii85FormatOptions {
+    /// Options for Adobe Ascii85, with delimiters.
+    pub fn adobe() -> Self {
+        Self::default().with_adobe_variant().with_delimiters(true)
+    }
+
+    /// Options for btoa.
+    pub fn btoa() -> Self {
+        Self::default().with_btoa_variant()
+    }
+
+    /// Options for ZeroMQ Z85.
+    pub fn z85() -> Self {
+        Self::default().with_z85_variant()
+    }
+
+    /// Sets the variant to be one of the values of the enum [`Ascii85Variant`].
+    pub fn with_variant(mut self, variant: Ascii85Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+    /// Sets the variant to [`Ascii85Variant::Adobe`].
+    pub fn with_adobe_variant(self) -> Self {
+        Self::with_variant(self, Ascii85Variant::Adobe)
+    }
+    /// Sets the variant to [`Ascii85Variant::Btoa`].
+    pub fn with_btoa_variant(self) -> Self {
+        Self::with_variant(self, Ascii85Variant::Btoa)
+    }
+    /// Sets the variant to [`Ascii85Variant::Z85`].
+    pub fn with_z85_variant(self) -> Self {
+        Self::with_variant(self, Ascii85Variant::Z85)
+    }
+
+    /// Enclose the representation in `<~` and `~>` delimiters, this only applies to the Adobe
+    /// variant.
+    pub fn with_delimiters(mut self, delimiters: bool) -> Self {
+        self.delimiters = delimiters;
+        self
+    }
+
+    pub(crate) fn check_length(&self, value: &Binary<'_>) -> Result<(), Error> {
+        if self.variant == Ascii85Variant::Z85 && !value.len().is_multiple_of(4) {
+            Err(Error::InvalidLength {
+                length: value.len(),
+                multiple_of: 4,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn has_delimiters(&self) -> bool {
+        self.delimiters && self.variant == Ascii85Variant::Adobe
+    }
+
+    fn strip_delimiters<'a>(&self, s: &'a str) -> Result<(usize, &'a str), ParseError> {
+        if self.variant != Ascii85Variant::Adobe {
+            return Ok((0, s));
+        }
+        let trimmed = s.trim_start();
+        let start = s.len() - trimmed.len();
+        match (
+            trimmed.strip_prefix(START_DELIMITER),
+            trimmed.trim_end().strip_suffix(END_DELIMITER),
+        ) {
+            (Some(_), Some(_)) => {
+                let content = &trimmed.trim_end()[START_DELIMITER.len()..];
+                Ok((
+                    start + START_DELIMITER.len(),
+                    &content[..content.len() - END_DELIMITER.len()],
+                ))
+            }
+            (None, None) if !self.delimiters => Ok((0, s)),
+            (Some(_), None) => Err(invalid_encoding(s, s.len(), 0, "`~>`")),
+            _ => Err(invalid_encoding(s, start, 2, "`<~`")),
+        }
+    }
+}
+
+impl Ascii85Variant {
+    fn symbol(&self, digit: u8) -> char {
+        match self {
+            Self::Adobe | Self::Btoa => (b'!' + digit) as char,
+            Self::Z85 => Z85_SYMBOLS[digit as usize] as char,
+        }
+    }
+
+    fn digit(&self, c: char) -> Option<u8> {
+        match self {
+            Self::Adobe | Self::Btoa => match c {
+                '!'..='u' => Some(c as u8 - b'!'),
+                _ => None,
+            },
+            Self::Z85 => Z85_SYMBOLS
+                .iter()
+                .position(|symbol| *symbol as char == c)
+                .map(|digit| digit as u8),
+        }
+    }
+
+    fn shortcut_for(&self, word: u32) -> Option<char> {
+        match (self, word) {
+            (Self::Adobe | Self::Btoa, 0x00000000) => Some('z'),
+            (Self::Btoa, 0x20202020) => Some('y'),
+            _ => None,
+        }
+    }
+
+    fn word_for_shortcut(&self, c: char) -> Option<u32> {
+        match (self, c) {
+            (Self::Adobe | Self::Btoa, 'z') => Some(0x00000000),
+            (Self::Btoa, 'y') => Some(0x20202020),
+            _ => None,
+        }
+    }
+}
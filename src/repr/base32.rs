@@ -0,0 +1,271 @@
+//!
+//! A base32 encoding of binary data, using one of the alphabets described in
+//! [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648) (base32 and base32hex) or
+//! [Crockford's Base32](https://www.crockford.com/base32.html), with optional padding and a
+//! choice of upper or lower case.
+//!
+//! When parsing, characters are matched without regard to case and padding is optional, but if
+//! present must be correct. The Crockford alphabet also ignores `-` characters and decodes the
+//! letters `I` and `L` as `1` and `O` as `0`.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-base32"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-base32", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, format, base32::Base32FormatOptions}
+//! };
+//!
+//! let binary = Binary::from("foobar");
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Base32FormatOptions::default()),
+//!     "MZXW6YTBOI======".to_string(),
+//! );
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Base32FormatOptions::default().with_extended_hex_alphabet().compact(true)),
+//!     "CPNMUOJ1E8".to_string(),
+//! );
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Base32FormatOptions::default().with_crockford_alphabet().lower_case(true).compact(true)),
+//!     "csqpyrk1e8".to_string(),
+//! );
+//! ```
+//!
+
+use crate::{
+    error::{invalid_encoding, ParseError},
+    repr::{format_with, BinaryFormatOptions},
+    Binary,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    matches,
+    option::Option,
+    result::Result::{self, Err, Ok},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Base32FormatOptions {
+    alphabet: Base32Alphabet,
+    padding: bool,
+    lower_case: bool,
+}
+
+///
+/// The alphabet used to encode 5-bit values as characters.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base32Alphabet {
+    /// The base32 alphabet from RFC 4648, `A`-`Z` followed by `2`-`7`.
+    #[default]
+    Rfc4648,
+    /// The *extended hex* alphabet from RFC 4648, `0`-`9` followed by `A`-`V`.
+    ExtendedHex,
+    /// Douglas Crockford's alphabet, `0`-`9` followed by the letters `A`-`Z` excluding `I`, `L`,
+    /// `O`, and `U`.
+    Crockford,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn base32_representation(value: &Binary<'_>, options: &Base32FormatOptions) -> String {
+    format_with(value.len().div_ceil(5) * 8, |buffer| {
+        write_base32_representation(buffer, value, options)
+    })
+}
+
+///
+/// Write the base32 representation of `value` to `w`, see [`base32_representation`].
+///
+pub fn write_base32_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &Base32FormatOptions,
+) -> fmt::Result {
+    let symbols = options.alphabet.symbols();
+    let mut length: usize = 0;
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut write_symbol = |w: &mut W, index: u32| {
+        let c = symbols[(index & 0x1F) as usize] as char;
+        length += 1;
+        w.write_char(if options.lower_case {
+            c.to_ascii_lowercase()
+        } else {
+            c
+        })
+    };
+    for byte in value.iter() {
+        bits = (bits << 8) | u32::from(*byte);
+        bit_count += 8;
+        while bit_count >= 5 {
+            bit_count -= 5;
+            write_symbol(w, bits >> bit_count)?;
+        }
+        bits &= (1 << bit_count) - 1;
+    }
+    if bit_count > 0 {
+        write_symbol(w, bits << (5 - bit_count))?;
+    }
+    if options.padding {
+        while !length.is_multiple_of(8) {
+            w.write_char('=')?;
+            length += 1;
+        }
+    }
+    Ok(())
+}
+
+///
+/// Parse a base32 representation using the RFC 4648 alphabet.
+///
+pub fn parse_base32_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    parse_base32_representation_with(s, &Base32FormatOptions::default())
+}
+
+///
+/// Parse a base32 representation using the alphabet in `options`.
+///
+pub fn parse_base32_representation_with<'a>(
+    s: &'a str,
+    options: &Base32FormatOptions,
+) -> Result<Binary<'a>, ParseError> {
+    let data = s.trim_end_matches('=');
+    let padding = s.len() - data.len();
+    let mut values = Vec::with_capacity(data.len() * 5 / 8);
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut symbol_count: usize = 0;
+    for (offset, c) in data.char_indices() {
+        if c == '-' && options.alphabet == Base32Alphabet::Crockford {
+            continue;
+        }
+        let value = options
+            .alphabet
+            .decode(c)
+            .ok_or_else(|| invalid_encoding(s, offset, c.len_utf8(), "a base32 symbol"))?;
+        bits = (bits << 5) | u32::from(value);
+        bit_count += 5;
+        symbol_count += 1;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            values.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+    // A final group of 1, 3, or 6 symbols cannot occur, and any left over bits must be zero.
+    if matches!(symbol_count % 8, 1 | 3 | 6) || bits != 0 {
+        return Err(invalid_encoding(
+            s,
+            data.len().saturating_sub(1),
+            1,
+            "a final base32 symbol with no left over bits",
+        ));
+    }
+    if padding > 0 && !(symbol_count + padding).is_multiple_of(8) || padding >= 8 {
+        return Err(invalid_encoding(
+            s,
+            data.len(),
+            padding,
+            "padding to a multiple of 8 symbols",
+        ));
+    }
+    Ok(Binary::from(values))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Base32FormatOptions> for BinaryFormatOptions {
+    fn from(value: Base32FormatOptions) -> Self {
+        Self::Base32(value)
+    }
+}
+
+impl Default for Base32FormatOptions {
+    fn default() -> Self {
+        Self {
+            alphabet: Base32Alphabet::default(),
+            padding: true,
+            lower_case: false,
+        }
+    }
+}
+
+impl Base32FormatOptions {
+    /// Sets the alphabet used to encode values to be one of the values of the enum
+    /// [`Base32Alphabet`].
+    pub fn with_alphabet(mut self, alphabet: Base32Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets the alphabet used to encode values to [`Base32Alphabet::Rfc4648`].
+    pub fn with_rfc4648_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base32Alphabet::Rfc4648)
+    }
+    /// Sets the alphabet used to encode values to [`Base32Alphabet::ExtendedHex`].
+    pub fn with_extended_hex_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base32Alphabet::ExtendedHex)
+    }
+    /// Sets the alphabet used to encode values to [`Base32Alphabet::Crockford`].
+    pub fn with_crockford_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base32Alphabet::Crockford)
+    }
+
+    /// Use a compact representation, this turns off base32 padding.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.padding = !compact;
+        self
+    }
+
+    /// Use lower case, rather than the default upper case, alpha characters.
+    pub fn lower_case(mut self, lower_case: bool) -> Self {
+        self.lower_case = lower_case;
+        self
+    }
+}
+
+impl Base32Alphabet {
+    const fn symbols(&self) -> &'static [u8; 32] {
+        match self {
+            Self::Rfc4648 => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            Self::ExtendedHex => b"0123456789ABCDEFGHIJKLMNOPQRSTUV",
+            Self::Crockford => b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+        }
+    }
+
+    fn decode(&self, c: char) -> Option<u8> {
+        let c = match (self, c.to_ascii_uppercase()) {
+            (Self::Crockford, 'O') => '0',
+            (Self::Crockford, 'I' | 'L') => '1',
+            (_, c) => c,
+        };
+        self.symbols()
+            .iter()
+            .position(|symbol| *symbol as char == c)
+            .map(|index| index as u8)
+    }
+}
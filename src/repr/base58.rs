@@ -0,0 +1,261 @@
+//!
+//! A base58 encoding of binary data, using either the Bitcoin or Flickr alphabet, as commonly
+//! used for blockchain addresses and IPFS content identifiers. Leading zero bytes are preserved as
+//! leading `1` characters.
+//!
+//! With the `repr-base58-check` feature enabled the *Base58Check* form may also be selected; this
+//! appends the first four bytes of a double SHA-256 hash of the value as a checksum when
+//! formatting, and verifies and removes it when parsing.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-base58"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-base58", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, format, base58::Base58FormatOptions}
+//! };
+//!
+//! let binary = Binary::from("Hello World!");
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Base58FormatOptions::default()),
+//!     "2NEpo7TZRRrLZSi2U".to_string(),
+//! );
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Base58FormatOptions::default().with_flickr_alphabet()),
+//!     "2nePN7syqqRkyrH2t".to_string(),
+//! );
+//! ```
+//!
+
+use crate::{
+    error::{invalid_encoding, Error, ParseError},
+    repr::{format_with, BinaryFormatOptions},
+    Binary,
+};
+use alloc::{string::String, vec, vec::Vec};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    result::Result::{self, Ok},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base58FormatOptions {
+    alphabet: Base58Alphabet,
+    check: bool,
+}
+
+///
+/// The alphabet used to encode base58 digits as characters.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base58Alphabet {
+    /// The alphabet used by Bitcoin, and IPFS, with upper case letters before lower case.
+    #[default]
+    Bitcoin,
+    /// The alphabet used by Flickr short URLs, with lower case letters before upper case.
+    Flickr,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn base58_representation(value: &Binary<'_>, options: &Base58FormatOptions) -> String {
+    format_with(value.len() * 138 / 100 + 1, |buffer| {
+        write_base58_representation(buffer, value, options)
+    })
+}
+
+///
+/// Write the base58 representation of `value` to `w`, see [`base58_representation`]. Unlike the
+/// other representations base58 is not formatted in a single pass, the digits are collected in
+/// a buffer as the whole value is converted before any is written.
+///
+pub fn write_base58_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &Base58FormatOptions,
+) -> fmt::Result {
+    let symbols = options.alphabet.symbols();
+    let bytes = options.with_checksum(value.as_ref());
+    let leading_zeros = bytes.iter().take_while(|b| **b == 0).count();
+
+    // Repeatedly divide the big-endian number in `bytes` by 58, collecting the remainders as
+    // little-endian base58 digits.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for byte in &bytes[leading_zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    for _ in 0..leading_zeros {
+        w.write_char(symbols[0] as char)?;
+    }
+    for digit in digits.iter().rev() {
+        w.write_char(symbols[*digit as usize] as char)?;
+    }
+    Ok(())
+}
+
+///
+/// Parse a base58 representation using the Bitcoin alphabet, without a checksum.
+///
+pub fn parse_base58_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    parse_base58_representation_with(s, &Base58FormatOptions::default())
+}
+
+///
+/// Parse a base58 representation using the alphabet in `options`, verifying and removing the
+/// checksum if *Base58Check* is enabled.
+///
+pub fn parse_base58_representation_with<'a>(
+    s: &'a str,
+    options: &Base58FormatOptions,
+) -> Result<Binary<'a>, ParseError> {
+    let symbols = options.alphabet.symbols();
+    let leading_zeros = s.chars().take_while(|c| *c == symbols[0] as char).count();
+
+    // Repeatedly multiply the little-endian number in `bytes` by 58, adding each digit.
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() * 733 / 1000 + 1);
+    for (offset, c) in s.char_indices().skip(leading_zeros) {
+        let mut carry = symbols
+            .iter()
+            .position(|symbol| *symbol as char == c)
+            .ok_or_else(|| invalid_encoding(s, offset, c.len_utf8(), "a base58 symbol"))?
+            as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xFF) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xFF) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut values = vec![0; leading_zeros];
+    values.extend(bytes.iter().rev());
+    if options.check {
+        options
+            .verify_checksum(&mut values)
+            .map_err(|error| error.at(s, 0, s.len(), None))?;
+    }
+    Ok(Binary::from(values))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Base58FormatOptions> for BinaryFormatOptions {
+    fn from(value: Base58FormatOptions) -> Self {
+        Self::Base58(value)
+    }
+}
+
+impl Base58FormatOptions {
+    /// Sets the alphabet used to encode values to be one of the values of the enum
+    /// [`Base58Alphabet`].
+    pub fn with_alphabet(mut self, alphabet: Base58Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets the alphabet used to encode values to [`Base58Alphabet::Bitcoin`].
+    pub fn with_bitcoin_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base58Alphabet::Bitcoin)
+    }
+    /// Sets the alphabet used to encode values to [`Base58Alphabet::Flickr`].
+    pub fn with_flickr_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base58Alphabet::Flickr)
+    }
+
+    /// Use the *Base58Check* form, adding a 4-byte double SHA-256 checksum when formatting and
+    /// verifying it when parsing.
+    #[cfg(feature = "repr-base58-check")]
+    pub fn use_check(mut self, check: bool) -> Self {
+        self.check = check;
+        self
+    }
+
+    #[cfg(feature = "repr-base58-check")]
+    fn with_checksum(&self, value: &[u8]) -> Vec<u8> {
+        let mut bytes = value.to_vec();
+        if self.check {
+            bytes.extend_from_slice(&checksum(value));
+        }
+        bytes
+    }
+
+    #[cfg(not(feature = "repr-base58-check"))]
+    fn with_checksum(&self, value: &[u8]) -> Vec<u8> {
+        value.to_vec()
+    }
+
+    #[cfg(feature = "repr-base58-check")]
+    fn verify_checksum(&self, values: &mut Vec<u8>) -> Result<(), Error> {
+        if values.len() < CHECKSUM_LENGTH {
+            return Result::Err(Error::ChecksumMismatch);
+        }
+        let payload_length = values.len() - CHECKSUM_LENGTH;
+        if checksum(&values[..payload_length]) != values[payload_length..] {
+            return Result::Err(Error::ChecksumMismatch);
+        }
+        values.truncate(payload_length);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "repr-base58-check"))]
+    fn verify_checksum(&self, _: &mut Vec<u8>) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl Base58Alphabet {
+    const fn symbols(&self) -> &'static [u8; 58] {
+        match self {
+            Self::Bitcoin => b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            Self::Flickr => b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "repr-base58-check")]
+const CHECKSUM_LENGTH: usize = 4;
+
+#[cfg(feature = "repr-base58-check")]
+fn checksum(value: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    use sha2::{Digest, Sha256};
+    let hash = Sha256::digest(Sha256::digest(value));
+    let mut checksum = [0; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&hash[..CHECKSUM_LENGTH]);
+    checksum
+}
@@ -1,5 +1,8 @@
 //!
-//! A standard base64 encoding of binary data with optional padding.
+//! A base64 encoding of binary data, as described in
+//! [RFC 4648](https://www.rfc-editor.org/rfc/rfc4648), using either the standard or URL-safe
+//! alphabet, with configurable padding and optional MIME ([RFC 2045](https://www.rfc-editor.org/rfc/rfc2045))
+//! or PEM ([RFC 7468](https://www.rfc-editor.org/rfc/rfc7468)) line wrapping.
 //!
 //! # Examples
 //!
@@ -22,18 +25,38 @@
 //!         Base64FormatOptions::default()),
 //!     "e+bU8iVcYtMhJKt+QPF7zhc8CNLRzswX".to_string(),
 //! );
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         Base64FormatOptions::default().with_url_safe_alphabet()),
+//!     "e-bU8iVcYtMhJKt-QPF7zhc8CNLRzswX".to_string(),
+//! );
 //! ```
 //!
 
-use crate::{error::Error, repr::BinaryFormatOptions, Binary};
-use alloc::string::String;
-use base64::prelude::{Engine as _, BASE64_STANDARD, BASE64_STANDARD_NO_PAD};
+use crate::{
+    error::{invalid_encoding, ParseError},
+    repr::{format_with, BinaryFormatOptions},
+    Binary,
+};
+use alloc::{string::String, vec::Vec};
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    prelude::Engine as _,
+    DecodeError,
+};
 use core::{
     clone::Clone,
     cmp::{Eq, PartialEq},
-    fmt::Debug,
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
     marker::Copy,
-    result::Result::{self, Ok},
+    option::Option::{self, None, Some},
+    result::Result,
+    str,
 };
 
 // ------------------------------------------------------------------------------------------------
@@ -42,7 +65,49 @@ use core::{
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Base64FormatOptions {
-    compact: bool,
+    alphabet: Base64Alphabet,
+    padding: Base64Padding,
+    line_wrap: Base64LineWrap,
+}
+
+///
+/// The alphabet used to encode 6-bit values as characters.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard alphabet, using `+` and `/` for the values 62 and 63.
+    #[default]
+    Standard,
+    /// The URL and filename safe alphabet, using `-` and `_` for the values 62 and 63.
+    UrlSafe,
+}
+
+///
+/// The policy for trailing `=` padding characters.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base64Padding {
+    /// Padding is generated, and is required when parsing.
+    #[default]
+    Required,
+    /// Padding is generated, but is optional when parsing.
+    Optional,
+    /// Padding is not generated, and is not allowed when parsing.
+    None,
+}
+
+///
+/// Line wrapping of long base64 representations.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Base64LineWrap {
+    /// No line wrapping, the representation is a single line.
+    #[default]
+    None,
+    /// Wrap lines at 64 characters, separated by `"\n"`, as used in PEM files.
+    Pem,
+    /// Wrap lines at 76 characters, separated by `"\r\n"`, as used in MIME messages.
+    Mime,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -50,16 +115,161 @@ pub struct Base64FormatOptions {
 // ------------------------------------------------------------------------------------------------
 
 pub fn base64_representation(value: &Binary<'_>, options: &Base64FormatOptions) -> String {
-    let engine = if options.compact {
-        BASE64_STANDARD_NO_PAD
+    let length = value.len().div_ceil(3) * 4;
+    let separators = options
+        .line_wrap
+        .line_length()
+        .map_or(0, |line_length| length / line_length);
+    format_with(
+        length + separators * options.line_wrap.line_separator().len(),
+        |buffer| write_base64_representation(buffer, value, options),
+    )
+}
+
+///
+/// Write the base64 representation of `value` to `w`, see [`base64_representation`]. The value
+/// is encoded a chunk at a time, into a fixed size buffer, and written as each chunk is encoded.
+///
+pub fn write_base64_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &Base64FormatOptions,
+) -> fmt::Result {
+    let engine = options.engine();
+    let separator = options.line_wrap.line_separator();
+    let mut encoded = [0_u8; ENCODE_CHUNK_LENGTH / 3 * 4];
+    let mut column = 0;
+    // Every chunk but the last is a multiple of three bytes, and so is never padded.
+    for chunk in value.chunks(ENCODE_CHUNK_LENGTH) {
+        let length = engine
+            .encode_slice(chunk, &mut encoded)
+            .map_err(|_| fmt::Error)?;
+        let mut rest = str::from_utf8(&encoded[..length]).map_err(|_| fmt::Error)?;
+        match options.line_wrap.line_length() {
+            None => w.write_str(rest)?,
+            Some(line_length) => {
+                // The encoded string is entirely ASCII so splitting bytes will not split
+                // characters.
+                while !rest.is_empty() {
+                    if column == line_length {
+                        w.write_str(separator)?;
+                        column = 0;
+                    }
+                    let (line, tail) = rest.split_at((line_length - column).min(rest.len()));
+                    w.write_str(line)?;
+                    column += line.len();
+                    rest = tail;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+/// Parse a base64 representation using the standard alphabet, with optional padding and no line
+/// wrapping.
+///
+pub fn parse_base64_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    parse_base64_representation_with(
+        s,
+        &Base64FormatOptions::default().with_padding(Base64Padding::Optional),
+    )
+}
+
+///
+/// Parse a base64 representation using the alphabet, padding, and line wrapping in `options`.
+/// When line wrapping is enabled any whitespace is ignored, otherwise it is an error.
+///
+pub fn parse_base64_representation_with<'a>(
+    s: &'a str,
+    options: &Base64FormatOptions,
+) -> Result<Binary<'a>, ParseError> {
+    let engine = options.engine();
+    if options.line_wrap == Base64LineWrap::None {
+        engine
+            .decode(s)
+            .map(Binary::from)
+            .map_err(|e| decode_error(s, e, s.len(), |offset| offset))
     } else {
-        BASE64_STANDARD
-    };
-    engine.encode(value.as_ref())
+        // Keep track of the offset of each retained byte so that errors refer to the original
+        // string rather than the unwrapped one.
+        let (offsets, unwrapped): (Vec<usize>, Vec<u8>) = s
+            .bytes()
+            .enumerate()
+            .filter(|(_, b)| !b.is_ascii_whitespace())
+            .unzip();
+        engine.decode(&unwrapped).map(Binary::from).map_err(|e| {
+            decode_error(s, e, unwrapped.len(), |offset| {
+                offsets.get(offset).copied().unwrap_or(s.len())
+            })
+        })
+    }
 }
 
-pub fn parse_base64_representation(s: &str) -> Result<Binary<'_>, Error> {
-    Ok(Binary::from(BASE64_STANDARD.decode(s).unwrap()))
+///
+/// Detect the options used to produce a base64 representation, returns `None` if the string
+/// contains characters outside of either alphabet, or is ambiguous: made only of letters and
+/// digits that are either all hex digits or not a whole number of four character groups.
+///
+pub(crate) fn detect_base64_options(s: &str) -> Option<Base64FormatOptions> {
+    let mut options = Base64FormatOptions::default();
+    let mut length: usize = 0;
+    let mut hex_digits = true;
+    let mut symbols = false;
+    for c in s.chars() {
+        match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' => hex_digits &= c.is_ascii_hexdigit(),
+            '=' => symbols = true,
+            '+' | '/' if options.alphabet == Base64Alphabet::Standard => symbols = true,
+            '-' | '_' => {
+                options = options.with_url_safe_alphabet();
+                symbols = true;
+            }
+            '\r' | '\n' => continue,
+            _ => return None,
+        }
+        length += 1;
+    }
+    if !symbols && (hex_digits || !length.is_multiple_of(4)) {
+        return None;
+    }
+    if s.contains('\n') {
+        let first_line = s.lines().next().map(str::len).unwrap_or_default();
+        options = options.with_line_wrap(if first_line == 64 {
+            Base64LineWrap::Pem
+        } else {
+            Base64LineWrap::Mime
+        });
+    }
+    Some(options.compact(!length.is_multiple_of(4)))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+// The number of bytes encoded at a time when streaming, a multiple of three.
+const ENCODE_CHUNK_LENGTH: usize = 768;
+
+///
+/// Convert a decoding error to an error located in `s`, `original_offset` maps the offset in the
+/// decoded bytes, `length` long, to the offset in `s`.
+///
+fn decode_error<F>(s: &str, error: DecodeError, length: usize, original_offset: F) -> ParseError
+where
+    F: Fn(usize) -> usize,
+{
+    let (offset, expected) = match error {
+        DecodeError::InvalidByte(offset, _) => (offset, "a base64 symbol"),
+        DecodeError::InvalidLastSymbol(offset, _) => {
+            (offset, "a final base64 symbol with no left over bits")
+        }
+        DecodeError::InvalidLength(length) => (length.saturating_sub(1), "a complete base64 group"),
+        DecodeError::InvalidPadding => (length, "padding to a multiple of 4 symbols"),
+    };
+    let offset = original_offset(offset);
+    invalid_encoding(s, offset, 1, expected)
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -73,9 +283,89 @@ impl From<Base64FormatOptions> for BinaryFormatOptions {
 }
 
 impl Base64FormatOptions {
+    /// Options for MIME content, standard alphabet with padding and lines wrapped at 76 characters.
+    pub fn mime() -> Self {
+        Self::default().with_line_wrap(Base64LineWrap::Mime)
+    }
+
+    /// Options for PEM content, standard alphabet with padding and lines wrapped at 64 characters.
+    pub fn pem() -> Self {
+        Self::default().with_line_wrap(Base64LineWrap::Pem)
+    }
+
+    /// Sets the alphabet used to encode values to be one of the values of the enum
+    /// [`Base64Alphabet`].
+    pub fn with_alphabet(mut self, alphabet: Base64Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+    /// Sets the alphabet used to encode values to [`Base64Alphabet::Standard`].
+    pub fn with_standard_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base64Alphabet::Standard)
+    }
+    /// Sets the alphabet used to encode values to [`Base64Alphabet::UrlSafe`].
+    pub fn with_url_safe_alphabet(self) -> Self {
+        Self::with_alphabet(self, Base64Alphabet::UrlSafe)
+    }
+
+    /// Sets the padding policy to be one of the values of the enum [`Base64Padding`].
+    pub fn with_padding(mut self, padding: Base64Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the line wrapping to be one of the values of the enum [`Base64LineWrap`].
+    pub fn with_line_wrap(mut self, line_wrap: Base64LineWrap) -> Self {
+        self.line_wrap = line_wrap;
+        self
+    }
+
     /// Use a compact representation, this turns off standard base64 padding.
     pub fn compact(mut self, compact: bool) -> Self {
-        self.compact = compact;
+        self.padding = if compact {
+            Base64Padding::None
+        } else {
+            Base64Padding::Required
+        };
         self
     }
+
+    fn engine(&self) -> GeneralPurpose {
+        let alphabet = match self.alphabet {
+            Base64Alphabet::Standard => &alphabet::STANDARD,
+            Base64Alphabet::UrlSafe => &alphabet::URL_SAFE,
+        };
+        let config = match self.padding {
+            Base64Padding::Required => GeneralPurposeConfig::new()
+                .with_encode_padding(true)
+                .with_decode_padding_mode(DecodePaddingMode::RequireCanonical),
+            Base64Padding::Optional => GeneralPurposeConfig::new()
+                .with_encode_padding(true)
+                .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+            Base64Padding::None => GeneralPurposeConfig::new()
+                .with_encode_padding(false)
+                .with_decode_padding_mode(DecodePaddingMode::RequireNone),
+        };
+        GeneralPurpose::new(alphabet, config)
+    }
+}
+
+impl Base64LineWrap {
+    /// Return the maximum number of characters per line, if wrapping.
+    pub const fn line_length(&self) -> Option<usize> {
+        match self {
+            Self::None => None,
+            Self::Pem => Some(64),
+            Self::Mime => Some(76),
+        }
+    }
+
+    /// Return the separator placed between lines.
+    pub const fn line_separator(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Pem => "\n",
+            Self::Mime => "\r\n",
+        }
+    }
 }
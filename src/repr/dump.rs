@@ -2,21 +2,16 @@
 //! Hexadecimal dump of a file.
 //!
 //! ```ebnf
-//! DumpRepresentation ::= [ HeaderLine ] { '\n' DataLine }
+//! DumpRepresentation ::= [ HeaderLine [ '\n' Underline ] ] { '\n' ( DataLine | SqueezeLine ) }
 //!
-//! HeaderLine ::= PrefixString ' '{4-7} (Column8   | Column16   | Column32  |
-//!                          Column2C8 | Column2C16 | Column2C32)
+//! HeaderLine ::= PrefixString ' '{4-7} Groups
 //!
-//! DataLine ::= LineIndex  (Column8   | Column16   | Column32  |
-//!                          Column2C8 | Column2C16 | Column2C32)
+//! DataLine ::= LineIndex Groups [ ' |' Char+ '|' ]
+//! SqueezeLine ::= '*'
 //! LineIndex ::= Nybble{3-6} ': '
 //!
-//! Column8 ::= Byte ( ' ' Byte ){0-7}
-//! Column2C8 ::= Column8 [ ' - ' Column8 ]
-//! Column16 ::= Byte ( ' ' Byte ){0-15}
-//! Column2C16 ::= Column16 [ ' - ' Column16 ]
-//! Column32 ::= Byte ( ' ' Byte ){0-31}
-//! Column2C32 ::= Column32 [ ' - ' Column32 ]
+//! Groups ::= Group ( Separator ' ' Group )*
+//! Group ::= Byte ( ' ' Byte )*
 //!
 //! Byte ::= Nybble Nybble Nybble?
 //! Nybble ::= [0-9a-fA-F]
@@ -60,27 +55,41 @@
 //!
 
 use crate::{
-    error::Error,
-    repr::{BinaryFormatOptions, ByteKind, RadixFormat, ReprComponentKind},
+    error::{offset_in, Error, ParseError},
+    repr::{
+        color::Theme, format_with, BinaryFormatOptions, ByteKind, Marker, Markup, RadixFormat,
+        ReprComponentKind, EXPECTED_RADIX_PREFIX,
+    },
     Binary,
 };
+#[cfg(feature = "repr-markdown")]
+use alloc::vec;
 use alloc::{
-    format,
+    borrow::Cow,
     string::{String, ToString},
+    vec::Vec,
 };
 use core::{
     assert,
     clone::Clone,
     cmp::{Eq, PartialEq},
+    convert::TryFrom,
     default::Default,
-    fmt::Debug,
+    fmt::{self, Debug, Display, Formatter, Write},
     iter::Iterator,
     marker::Copy,
+    matches,
+    ops::Range,
     option::Option::{self, None, Some},
-    result::Result,
-    todo, unreachable,
+    result::Result::{self, Err, Ok},
+    unreachable,
 };
 
+#[cfg(feature = "repr-color")]
+use crate::repr::color::{ColorPolicy, Style};
+#[cfg(feature = "repr-color")]
+use anstyle::{AnsiColor, Color};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -92,15 +101,27 @@ pub struct DumpFormatOptions {
     index_header_line: bool,
     index_line_numbers: bool,
     index_radix_format: RadixFormat,
-    column_width: DumpColumnWidth,
-    two_columns: bool,
+    bytes_per_line: usize,
+    group_size: usize,
+    word_size: DumpWordSize,
+    byte_order: DumpByteOrder,
+    word_format: DumpWordFormat,
     show_ascii: bool,
     show_extended_ascii: bool,
+    code_page: DumpCodePage,
+    replacement_char: char,
+    ascii_gutter: bool,
+    squeeze: bool,
+    base_address: usize,
+    offset: usize,
+    length: Option<usize>,
+    annotations: Vec<DumpAnnotation>,
     line_index_spacing: String,
     value_spacing: String,
     column_separator: char,
     column_index_underline: Option<char>,
-    colored: bool,
+    theme: Theme,
+    markup: Markup,
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -112,74 +133,575 @@ pub enum DumpColumnWidth {
     ThirtyTwo = 32,
 }
 
+///
+/// The number of bytes in each word, or cell, of a dump.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(usize)]
+pub enum DumpWordSize {
+    #[default]
+    One = 1,
+    Two = 2,
+    Four = 4,
+    Eight = 8,
+}
+
+///
+/// The order of the bytes within a word of a dump, determining its value.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DumpByteOrder {
+    /// The first byte is the most significant, words show bytes in the order they are stored.
+    #[default]
+    BigEndian,
+    /// The first byte is the least significant, in the style of `xxd -e`.
+    LittleEndian,
+}
+
+///
+/// How the value of each word of a dump is shown.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DumpWordFormat {
+    /// An unsigned integer, in the radix format of the dump, padded with zeros.
+    #[default]
+    Unsigned,
+    /// A signed, two's complement, integer in the radix format of the dump.
+    Signed,
+    /// An IEEE 754 float, in decimal exponent form; this is only possible for words of four or
+    /// eight bytes, other words are shown as unsigned integers.
+    Float,
+}
+
+///
+/// The mapping from bytes to the characters shown for them, by [`DumpFormatOptions::show_ascii`]
+/// and in the ASCII gutter.
+///
+#[derive(Clone, Copy, Debug, Default, Eq)]
+pub enum DumpCodePage {
+    /// ISO 8859-1. When [`DumpFormatOptions::show_extended_ascii`] is set the C0 control
+    /// characters, space, and DEL are shown as glyphs of the Unicode Control Pictures block.
+    #[default]
+    Latin1,
+    /// The original IBM PC code page, 437, including its glyphs for control characters.
+    Cp437,
+    /// The EBCDIC code page 037, used by IBM mainframes.
+    Cp037,
+    /// UTF-8, a multi-byte character is shown in the cell of its first byte and the cells of its
+    /// remaining bytes are left blank. A character split across lines has no printable form.
+    Utf8,
+    /// A user supplied mapping, returning `None` for bytes that have no printable form.
+    Custom(fn(u8) -> Option<char>),
+}
+
+///
+/// A named range of bytes in a dump; the bytes in the range are highlighted, when color is used,
+/// and the label is shown at the end of each line that contains any of them.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DumpAnnotation {
+    range: Range<usize>,
+    label: String,
+    #[cfg(feature = "repr-color")]
+    style: Option<Style>,
+}
+
+///
+/// Options for a side-by-side dump of two values, see [`diff_dump_representation`].
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DumpDiffOptions {
+    layout: DumpFormatOptions,
+    context: Option<usize>,
+}
+
+///
+/// A summary of the differences between two values.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DumpDiffSummary {
+    differing_bytes: usize,
+    first_difference: Option<usize>,
+    left_length: usize,
+    right_length: usize,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
 pub fn dump_representation(value: &Binary<'_>, options: &DumpFormatOptions) -> String {
+    format_with(value.len() * 4, |buffer| {
+        write_dump_representation(buffer, value, options)
+    })
+}
+
+///
+/// Write the dump of `value` to `w`, see [`dump_representation`].
+///
+pub fn write_dump_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &DumpFormatOptions,
+) -> fmt::Result {
     // --------------------------------------------------------------------------------------------
     // This is not supported the line indexes get ridiculous.
     // --------------------------------------------------------------------------------------------
     assert!(options.index_radix_format != RadixFormat::Binary);
 
-    let (mid, end) = options.byte_counts();
-    let mut buffer = String::default();
+    let options = &options.resolve_markup();
+    let end = options.bytes_per_line;
+    let index_width = options.line_index_width(value.len());
 
     // --------------------------------------------------------------------------------------------
     // Header line(s).
     // --------------------------------------------------------------------------------------------
     if options.index_header_line {
-        buffer.push_str(&format!(
-            "{:1$}{2:3$}",
-            options.radix_format.prefix_str(),
-            options.line_index_width(),
-            "",
-            options.line_index_spacing.len(),
-        ));
-        for i in 0..end {
-            buffer.push_str(&options.format_column_index(i));
-            if (i + 1) % end != 0 && options.two_columns && (i + 1) % mid == 0 {
-                buffer.push_str(&options.format_column_separator());
-            }
+        options.write_header_prefix(w, index_width)?;
+        options.write_header_columns(w)?;
+        w.write_char('\n')?;
+        if let Some(underline) = options.column_index_underline {
+            options.write_header_indent(w, index_width)?;
+            options.write_header_underline(w, underline)?;
+            w.write_char('\n')?;
         }
-        buffer.push('\n');
-        if let Some(underline) = options.format_header_underline() {
-            buffer.push_str(&format!(
-                "{:1$}",
-                "",
-                options.line_index_width() + options.line_index_spacing.len()
-            ));
-            buffer.push_str(&underline);
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Actual data formatting.
+    // --------------------------------------------------------------------------------------------
+    for span in options.visible_line_spans(value) {
+        let Some((line_index, leading, range)) = span else {
+            options.write_squeeze_marker(w)?;
+            continue;
+        };
+        let position = range.start;
+        let bytes = &value[range];
+        let full = leading + bytes.len() == end;
+        let labelled = options.is_annotated(position..position + bytes.len());
+        options.write_line_index(w, line_index, index_width)?;
+        options.write_values(w, bytes, leading, position, None)?;
+        if options.ascii_gutter || labelled {
+            options.write_line_padding(w, leading, bytes.len())?;
+        }
+        if options.ascii_gutter {
+            options.write_ascii_gutter(w, bytes, leading, position, None)?;
+        }
+        if labelled {
+            options.write_annotation_labels(w, position..position + bytes.len())?;
+        }
+        if full {
+            w.write_char('\n')?;
+        }
+    }
+    Ok(())
+}
+
+///
+/// Dump two values side by side, the left value followed by the right value on each line, using
+/// the layout of the dump options. Bytes that differ from the corresponding byte of the other
+/// value are highlighted, when color is used, and the divider between the two values on a line
+/// is `≠` rather than `│` where the line differs. The dump ends with a summary of the
+/// differences, see [`DumpDiffSummary`].
+///
+/// The options for squeezing lines and annotations are ignored.
+///
+pub fn diff_dump_representation(
+    left: &Binary<'_>,
+    right: &Binary<'_>,
+    options: &DumpDiffOptions,
+) -> String {
+    format_with((left.len() + right.len()) * 4, |buffer| {
+        write_diff_dump_representation(buffer, left, right, options)
+    })
+}
+
+///
+/// Write the side by side dump of two values to `w`, see [`diff_dump_representation`].
+///
+pub fn write_diff_dump_representation<W: Write + ?Sized>(
+    w: &mut W,
+    left: &Binary<'_>,
+    right: &Binary<'_>,
+    options: &DumpDiffOptions,
+) -> fmt::Result {
+    let layout = &options.layout.resolve_markup();
+    assert!(layout.index_radix_format != RadixFormat::Binary);
+    let index_width = layout.line_index_width(left.len().max(right.len()));
+
+    // --------------------------------------------------------------------------------------------
+    // Header line(s).
+    // --------------------------------------------------------------------------------------------
+    if layout.index_header_line {
+        layout.write_header_prefix(w, index_width)?;
+        layout.write_header_columns(w)?;
+        layout.write_diff_divider(w, false, true)?;
+        layout.write_header_columns(w)?;
+        w.write_char('\n')?;
+        if let Some(underline) = layout.column_index_underline {
+            layout.write_header_indent(w, index_width)?;
+            layout.write_header_underline(w, underline)?;
+            layout.write_diff_divider(w, false, true)?;
+            layout.write_header_underline(w, underline)?;
+            w.write_char('\n')?;
         }
     }
 
     // --------------------------------------------------------------------------------------------
     // Actual data formatting.
     // --------------------------------------------------------------------------------------------
-    for (index, byte) in value.iter().enumerate() {
-        let one_index = index + 1;
+    let side = |value: &'_ Binary<'_>, range: &Range<usize>| -> Range<usize> {
+        range.start.min(value.len())..range.end.min(value.len())
+    };
+    let (start, stop) = layout.window(left.len().max(right.len()));
+    let spans = layout.line_spans(start, stop);
+    let differing: Vec<bool> = spans
+        .iter()
+        .map(|(_, _, range)| range.clone().any(|i| left.get(i) != right.get(i)))
+        .collect();
+    let shown = |line: usize| match options.context {
+        None => true,
+        Some(context) => {
+            let first = line.saturating_sub(context);
+            let last = (line + context).min(differing.len() - 1);
+            differing[first..=last].contains(&true)
+        }
+    };
+    let mut omitted = false;
+    for (line, (line_index, leading, range)) in spans.iter().enumerate() {
+        if !shown(line) {
+            if !omitted {
+                layout.write_squeeze_marker(w)?;
+                omitted = true;
+            }
+            continue;
+        }
+        omitted = false;
+        let left_bytes = &left[side(left, range)];
+        let right_bytes = &right[side(right, range)];
+        layout.write_line_index(w, *line_index, index_width)?;
+        for (bytes, other, is_left) in [
+            (left_bytes, right_bytes, true),
+            (right_bytes, left_bytes, false),
+        ] {
+            layout.write_values(w, bytes, *leading, range.start, Some(other))?;
+            if is_left || layout.ascii_gutter {
+                layout.write_line_padding(w, *leading, bytes.len())?;
+            }
+            if layout.ascii_gutter {
+                layout.write_ascii_gutter(w, bytes, *leading, range.start, Some(other))?;
+                if is_left {
+                    let filled = leading + bytes.len();
+                    write!(w, "{:1$}", "", layout.bytes_per_line - filled)?;
+                }
+            }
+            if is_left {
+                layout.write_diff_divider(w, differing[line], false)?;
+            }
+        }
+        w.write_char('\n')?;
+    }
+    write!(w, "{}", diff_summary(left, right))
+}
 
-        if options.index_line_numbers && index == 0 || index % end == 0 {
-            buffer.push_str(&options.format_line_index(index));
+///
+/// Summarize the differences between two values, comparing each byte with the byte at the same
+/// offset in the other value; bytes beyond the end of the shorter value all count as differing.
+///
+pub fn diff_summary(left: &Binary<'_>, right: &Binary<'_>) -> DumpDiffSummary {
+    let common = left.len().min(right.len());
+    let differing = (0..common).filter(|i| left[*i] != right[*i]);
+    let first_difference = differing.clone().next().or(if left.len() != right.len() {
+        Some(common)
+    } else {
+        None
+    });
+    DumpDiffSummary {
+        differing_bytes: differing.count() + left.len().max(right.len()) - common,
+        first_difference,
+        left_length: left.len(),
+        right_length: right.len(),
+    }
+}
+
+///
+/// Lay out a dump as a table, for renderers such as Markdown, returning the header row and the
+/// rows of data. There is a column for the line index, one for each cell of a line, and ones for
+/// the ASCII gutter and annotation labels where these are shown; cells are unstyled and have no
+/// padding. A run of squeezed lines is a row with `*` as its index and no values.
+///
+#[cfg(feature = "repr-markdown")]
+pub(crate) fn dump_table(
+    value: &Binary<'_>,
+    options: &DumpFormatOptions,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    assert!(options.index_radix_format != RadixFormat::Binary);
+    let plain = DumpFormatOptions {
+        line_index_spacing: String::default(),
+        value_spacing: String::default(),
+        markup: Markup::None,
+        ..options.clone()
+    };
+    let word = plain.word_size.byte_count();
+    let index_width = plain.line_index_width(value.len());
+    let extra_columns = [
+        (plain.ascii_gutter, "Text"),
+        (!plain.annotations.is_empty(), "Labels"),
+    ];
+
+    let mut header = vec!["Offset".to_string()];
+    header.extend((0..plain.cells_per_line()).map(|i| {
+        format_with(plain.data_value_width(), |buffer| {
+            plain.write_column_index(buffer, i * word)
+        })
+    }));
+    header.extend(
+        extra_columns
+            .iter()
+            .filter(|(shown, _)| *shown)
+            .map(|(_, title)| title.to_string()),
+    );
+
+    let mut rows = Vec::default();
+    for span in plain.visible_line_spans(value) {
+        let Some((line_index, leading, range)) = span else {
+            let mut row = vec!["*".to_string()];
+            row.resize(header.len(), String::default());
+            rows.push(row);
+            continue;
+        };
+        let position = range.start;
+        let bytes = &value[range];
+        let chars = plain.decode_line(bytes);
+        let mut row = vec![format_with(index_width, |buffer| {
+            plain.write_line_index(buffer, line_index, index_width)
+        })];
+        for index in 0..plain.cells_per_line() {
+            row.push(match (index * word).checked_sub(leading) {
+                Some(i) if i < bytes.len() => format_with(plain.data_value_width(), |buffer| {
+                    plain.write_cell(buffer, bytes, &chars, i, position, None)
+                })
+                .trim()
+                .to_string(),
+                _ => String::default(),
+            });
+        }
+        if plain.ascii_gutter {
+            row.push(format_with(leading + bytes.len(), |buffer| {
+                write!(buffer, "{:leading$}", "")?;
+                plain.write_gutter_chars(buffer, bytes, position, None)
+            }));
         }
+        if !plain.annotations.is_empty() {
+            row.push(
+                plain
+                    .annotation_labels(position..position + bytes.len())
+                    .map(|label| label.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+        rows.push(row);
+    }
+    (header, rows)
+}
 
-        if options.show_ascii {
-            buffer.push_str(&options.format_ascii_char(byte));
-        } else {
-            buffer.push_str(&options.format_data_value(*byte));
+///
+/// Parse a dump, as produced by [`dump_representation`], back into a `Binary` value.
+///
+/// The optional header line is used to determine the radix of the data values and the position
+/// of any column separators; if it is not present the default [`RadixFormat`] is assumed and any
+/// single, non-alphanumeric, token is treated as a column separator. Any underline following the
+/// header is skipped, as are ANSI escape sequences added by color styling. The index at the start
+/// of each line is checked against the number of bytes parsed so far, and any ASCII gutter at the
+/// end of a line is ignored, as are annotation labels. A line containing only `*` is expanded
+/// into copies of the line before it, up to the index of the line that follows. As the index of
+/// the first line must be zero, dumps produced with a base address or an offset cannot be
+/// parsed; nor can dumps of multi-byte words, or signed or float values.
+///
+pub fn parse_dump_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    let mut lines = s
+        .lines()
+        .map(|original| (original, strip_ansi_escapes(original)))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+
+    // --------------------------------------------------------------------------------------------
+    // Header line, and underline, if present.
+    // --------------------------------------------------------------------------------------------
+    let layout = match lines.peek() {
+        Some((original, line)) if !is_data_line(line) => {
+            let layout = DumpLayout::from_header(line)
+                .map_err(|error| locate_in_line(s, original, line, error))?;
+            let _ = lines.next();
+            if let Some((_, line)) = lines.peek() {
+                if !line.chars().any(char::is_alphanumeric) {
+                    let _ = lines.next();
+                }
+            }
+            layout
+        }
+        _ => DumpLayout::default(),
+    };
+
+    // --------------------------------------------------------------------------------------------
+    // Actual data parsing.
+    // --------------------------------------------------------------------------------------------
+    let mut values: Vec<u8> = Vec::default();
+    let mut line_length: Option<usize> = layout.line_length;
+    let mut line_number = 0;
+    let mut squeezed = false;
+    let mut parse_line = |line: &str| -> Result<(), ParseError> {
+        let whole_line = |error: Error, expected| error.at(line, 0, line.len(), Some(expected));
+        if line.trim() == "*" {
+            if squeezed || values.is_empty() {
+                return Err(whole_line(
+                    Error::InvalidRepresentation,
+                    "a data line, a squeeze marker may only follow one",
+                ));
+            }
+            squeezed = true;
+            return Ok(());
+        }
+        let Some((index, data)) = line.split_once(':') else {
+            return Err(whole_line(
+                Error::InvalidRepresentation,
+                "a data line starting with an index and `:`",
+            ));
+        };
+        let index = index.trim();
+        let invalid_index = |expected: usize| {
+            Error::InvalidLineOffset {
+                expected,
+                found: index.to_string(),
+            }
+            .at(
+                line,
+                offset_in(line, index),
+                index.len(),
+                Some("the offset of the first byte in the line"),
+            )
+        };
+        if squeezed {
+            // Repeat the line before the marker until reaching the index of this line.
+            let invalid_squeeze = || {
+                whole_line(
+                    Error::InvalidRepresentation,
+                    "a squeeze marker following a complete line",
+                )
+            };
+            let line_length = line_length.ok_or_else(invalid_squeeze)?;
+            let previous = values
+                .len()
+                .checked_sub(line_length)
+                .ok_or_else(invalid_squeeze)?;
+            let repeats = squeezed_line_count(index, values.len(), line_length)
+                .ok_or_else(|| invalid_index(values.len()))?;
+            for _ in 0..repeats {
+                values.extend_from_within(previous..previous + line_length);
+            }
+            line_number += repeats;
+            squeezed = false;
         }
+        let expected = values.len();
+        if let Some(line_length) = line_length {
+            if expected != line_number * line_length {
+                return Err(whole_line(
+                    Error::InvalidRepresentation,
+                    "a complete line before this one",
+                ));
+            }
+        }
+        if !line_index_matches(index, expected) {
+            return Err(invalid_index(expected));
+        }
+        line_number += 1;
 
-        if one_index % end == 0 {
-            buffer.push('\n');
-        } else if options.two_columns && one_index > 0 && one_index % mid == 0 {
-            buffer.push_str(&options.format_column_separator());
+        let line_values = parse_line_values(line, data, &layout)?;
+        let line_count = line_values.len();
+        values.extend(line_values);
+        if line_length.is_none() {
+            line_length = Some(line_count);
         }
+        Ok(())
+    };
+    for (original, line) in lines {
+        parse_line(&line).map_err(|error| locate_in_line(s, original, &line, error))?;
     }
-    buffer
+    if squeezed {
+        return Err(Error::InvalidRepresentation.at(
+            s,
+            s.len(),
+            0,
+            Some("a data line following the squeeze marker"),
+        ));
+    }
+    Ok(Binary::from(values))
 }
 
-pub fn parse_dump_representation(_s: &str) -> Result<Binary<'_>, Error> {
-    todo!()
+///
+/// Detect the options used to produce a dump, as far as they can be determined from the header
+/// line. Returns `None` if the string does not look like a dump at all.
+///
+pub(crate) fn detect_dump_options(s: &str) -> Option<DumpFormatOptions> {
+    let lines: Vec<Cow<'_, str>> = s
+        .lines()
+        .map(strip_ansi_escapes)
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let indices: Vec<&str> = lines
+        .iter()
+        .filter(|line| is_data_line(line))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(index, _)| index.trim())
+        .collect();
+    let squeeze = lines.iter().any(|line| line.trim() == "*");
+    let ascii_gutter = lines
+        .iter()
+        .filter(|line| is_data_line(line))
+        .any(|line| line.trim_end().ends_with('|') && line.contains("  |"));
+    let first = lines.first()?;
+    if is_data_line(first) {
+        return Some(
+            DumpFormatOptions::default()
+                .has_index_header_line(false)
+                .show_ascii_gutter(ascii_gutter)
+                .squeeze(squeeze),
+        );
+    }
+    let layout = DumpLayout::from_header(first).ok()?;
+    let line_length = layout.line_length.unwrap_or_default();
+    if line_length == 0 {
+        return None;
+    }
+
+    let mut options = DumpFormatOptions::default()
+        .show_ascii_gutter(ascii_gutter)
+        .squeeze(squeeze)
+        .with_byte_radix_format(layout.radix_format)
+        .with_index_radix_format(detect_index_radix(
+            &indices,
+            line_length,
+            layout.radix_format,
+        ));
+    options = match lines.get(1) {
+        Some(line) if !line.chars().any(char::is_alphanumeric) => {
+            match line.trim().chars().next() {
+                Some(underline) => options.underline_column_index_with(underline),
+                None => options.no_column_index_underline(),
+            }
+        }
+        _ => options.no_column_index_underline(),
+    };
+    let options = options.with_groups_of(1, line_length);
+    let separator = layout.separator.unwrap_or_default();
+    let mut separator = separator.chars();
+    Some(match (separator.next(), separator.next()) {
+        (Some(separator), None) => options
+            .with_group_size(layout.separator_positions.first().copied()?)
+            .separate_columns_with(separator),
+        _ => options,
+    })
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -192,6 +714,16 @@ impl From<DumpFormatOptions> for BinaryFormatOptions {
     }
 }
 
+impl PartialEq for DumpCodePage {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Custom mappings are equal only if they are the same function.
+            (Self::Custom(lhs), Self::Custom(rhs)) => core::ptr::fn_addr_eq(*lhs, *rhs),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
 impl Default for DumpFormatOptions {
     fn default() -> Self {
         Self {
@@ -200,15 +732,30 @@ impl Default for DumpFormatOptions {
             index_header_line: true,
             index_line_numbers: true,
             index_radix_format: RadixFormat::default(),
-            column_width: DumpColumnWidth::default(),
-            two_columns: true,
+            bytes_per_line: 16,
+            group_size: 8,
+            word_size: DumpWordSize::default(),
+            byte_order: DumpByteOrder::default(),
+            word_format: DumpWordFormat::default(),
             show_ascii: false,
             show_extended_ascii: false,
+            code_page: DumpCodePage::default(),
+            replacement_char: '.',
+            ascii_gutter: false,
+            squeeze: false,
+            base_address: 0,
+            offset: 0,
+            length: None,
+            annotations: Vec::default(),
             line_index_spacing: ":  ".to_string(),
             value_spacing: " ".to_string(),
             column_separator: '│',
             column_index_underline: Some('─'),
-            colored: cfg!(feature = "repr-color"),
+            theme: Theme::default(),
+            #[cfg(feature = "repr-color")]
+            markup: Markup::from_policy(ColorPolicy::default()),
+            #[cfg(not(feature = "repr-color"))]
+            markup: Markup::None,
         }
     }
 }
@@ -236,6 +783,22 @@ impl DumpFormatOptions {
             .show_extended_ascii(true)
     }
 
+    ///
+    /// A dump in the style of `hexdump -C`, lower hex bytes in two columns of eight followed by a
+    /// gutter of the corresponding characters, with no header line.
+    ///
+    pub fn canonical_hex_dump() -> Self {
+        Self::default()
+            .with_lower_hex_bytes()
+            .with_lower_hex_indices()
+            .compact(false)
+            .has_index_header_line(false)
+            .two_columns_of(DumpColumnWidth::Eight)
+            .separate_columns_with(' ')
+            .show_ascii(false)
+            .show_ascii_gutter(true)
+    }
+
     pub fn hex_dump() -> Self {
         Self::default()
             .with_upper_hex_bytes()
@@ -356,35 +919,104 @@ impl DumpFormatOptions {
         Self::with_index_radix_format(self, RadixFormat::UpperHex)
     }
 
-    pub fn has_two_columns(mut self, two_columns: bool) -> Self {
-        self.two_columns = two_columns;
+    /// Sets the number of bytes on each line, at least one; the default is 16. Column indices in
+    /// the header wrap to zero where they would be wider than a cell, such as past `FF` on lines
+    /// of more than 256 bytes.
+    pub fn with_bytes_per_line(mut self, bytes_per_line: usize) -> Self {
+        self.bytes_per_line = bytes_per_line.max(1);
         self
     }
 
-    pub fn with_column_width(mut self, column_width: DumpColumnWidth) -> Self {
-        self.column_width = column_width;
+    /// Sets the number of bytes in each group on a line, at least one, groups are separated by
+    /// the column separator; the default is 8. A group size that is not less than the number of
+    /// bytes per line results in a single group.
+    pub fn with_group_size(mut self, group_size: usize) -> Self {
+        self.group_size = group_size.max(1);
         self
     }
 
-    pub fn one_column_of(mut self, column_width: DumpColumnWidth) -> Self {
-        self.two_columns = false;
-        self.column_width = column_width;
+    /// Sets the number of bytes in each word, or cell, of a dump in the style of `xxd -g` and
+    /// `od -t`; the default is [`DumpWordSize::One`]. The number of bytes per line, and in each
+    /// group, should be multiples of the word size, as should any offset and base address for
+    /// words to align with the header.
+    pub fn with_word_size(mut self, word_size: DumpWordSize) -> Self {
+        self.word_size = word_size;
         self
     }
 
-    pub fn two_columns_of(mut self, column_width: DumpColumnWidth) -> Self {
-        self.two_columns = true;
-        self.column_width = column_width;
+    /// Sets the order of bytes within words to be one of the values of the enum
+    /// [`DumpByteOrder`].
+    pub fn with_byte_order(mut self, byte_order: DumpByteOrder) -> Self {
+        self.byte_order = byte_order;
         self
     }
+    /// Sets the order of bytes within words to [`DumpByteOrder::BigEndian`].
+    pub fn with_big_endian_words(self) -> Self {
+        Self::with_byte_order(self, DumpByteOrder::BigEndian)
+    }
+    /// Sets the order of bytes within words to [`DumpByteOrder::LittleEndian`].
+    pub fn with_little_endian_words(self) -> Self {
+        Self::with_byte_order(self, DumpByteOrder::LittleEndian)
+    }
+
+    /// Sets how the value of each word is shown to be one of the values of the enum
+    /// [`DumpWordFormat`].
+    pub fn with_word_format(mut self, word_format: DumpWordFormat) -> Self {
+        self.word_format = word_format;
+        self
+    }
+    /// Sets how the value of each word is shown to [`DumpWordFormat::Unsigned`].
+    pub fn with_unsigned_words(self) -> Self {
+        Self::with_word_format(self, DumpWordFormat::Unsigned)
+    }
+    /// Sets how the value of each word is shown to [`DumpWordFormat::Signed`].
+    pub fn with_signed_words(self) -> Self {
+        Self::with_word_format(self, DumpWordFormat::Signed)
+    }
+    /// Sets how the value of each word is shown to [`DumpWordFormat::Float`].
+    pub fn with_float_words(self) -> Self {
+        Self::with_word_format(self, DumpWordFormat::Float)
+    }
+
+    /// Sets the number of groups on each line, and the number of bytes in each group.
+    pub fn with_groups_of(self, group_count: usize, group_size: usize) -> Self {
+        self.with_group_size(group_size)
+            .with_bytes_per_line(group_count.max(1) * group_size)
+    }
+
+    /// Sets the number of groups on each line to two or one, keeping the group size.
+    pub fn has_two_columns(self, two_columns: bool) -> Self {
+        let group_count = if two_columns { 2 } else { 1 };
+        let group_size = self.group_size;
+        self.with_groups_of(group_count, group_size)
+    }
+
+    /// Sets the number of bytes in each group, keeping the number of groups.
+    pub fn with_column_width(self, column_width: DumpColumnWidth) -> Self {
+        let group_count = self.bytes_per_line.div_ceil(self.group_size);
+        self.with_groups_of(group_count, column_width.byte_count())
+    }
+
+    pub fn one_column_of(self, column_width: DumpColumnWidth) -> Self {
+        self.with_groups_of(1, column_width.byte_count())
+    }
+
+    pub fn two_columns_of(self, column_width: DumpColumnWidth) -> Self {
+        self.with_groups_of(2, column_width.byte_count())
+    }
 
     pub fn compact(mut self, compact: bool) -> Self {
         self.compact = compact;
         self
     }
 
+    /// Show printable bytes as characters in place of their data values. As characters are
+    /// shown in the width of a hexadecimal value, showing them also selects upper case
+    /// hexadecimal bytes; hiding them leaves the byte radix format unchanged.
     pub fn show_ascii(mut self, show_ascii: bool) -> Self {
-        self = self.with_upper_hex_bytes();
+        if show_ascii {
+            self = self.with_upper_hex_bytes();
+        }
         self.show_ascii = show_ascii;
         self
     }
@@ -395,180 +1027,785 @@ impl DumpFormatOptions {
         self
     }
 
-    /// Use color to denote byte kind according the ASCII conventions denoted by the
-    /// enums `ByteStyle` and `ReprStyle`.
+    /// Follow the data values on each line with a gutter, enclosed in `|` characters, showing each
+    /// byte as a character in the same way as [`Self::show_ascii`], with the replacement
+    /// character for bytes that have no printable form.
+    pub fn show_ascii_gutter(mut self, ascii_gutter: bool) -> Self {
+        self.ascii_gutter = ascii_gutter;
+        self
+    }
+
+    /// Sets the mapping from bytes to characters to one of the values of the enum
+    /// [`DumpCodePage`]. Dumps that show characters in place of data values can only be parsed
+    /// when using [`DumpCodePage::Latin1`].
+    pub fn with_code_page(mut self, code_page: DumpCodePage) -> Self {
+        self.code_page = code_page;
+        self
+    }
+    /// Sets the mapping from bytes to characters to [`DumpCodePage::Latin1`].
+    pub fn with_latin1_code_page(self) -> Self {
+        Self::with_code_page(self, DumpCodePage::Latin1)
+    }
+    /// Sets the mapping from bytes to characters to [`DumpCodePage::Cp437`].
+    pub fn with_cp437_code_page(self) -> Self {
+        Self::with_code_page(self, DumpCodePage::Cp437)
+    }
+    /// Sets the mapping from bytes to characters to [`DumpCodePage::Cp037`].
+    pub fn with_cp037_code_page(self) -> Self {
+        Self::with_code_page(self, DumpCodePage::Cp037)
+    }
+    /// Sets the mapping from bytes to characters to [`DumpCodePage::Utf8`].
+    pub fn with_utf8_code_page(self) -> Self {
+        Self::with_code_page(self, DumpCodePage::Utf8)
+    }
+    /// Sets the mapping from bytes to characters to the function `mapping`, which returns `None`
+    /// for bytes that have no printable form.
+    pub fn with_char_mapping(self, mapping: fn(u8) -> Option<char>) -> Self {
+        Self::with_code_page(self, DumpCodePage::Custom(mapping))
+    }
+
+    /// Sets the character shown in the ASCII gutter for bytes that have no printable form; the
+    /// default is `.`.
+    pub fn with_replacement_char(mut self, replacement_char: char) -> Self {
+        self.replacement_char = replacement_char;
+        self
+    }
+
+    /// Replace consecutive lines identical to the line before them with a single line containing
+    /// `*`, in the style of `hexdump` and `xxd -a`. The last line is always shown.
+    pub fn squeeze(mut self, squeeze: bool) -> Self {
+        self.squeeze = squeeze;
+        self
+    }
+
+    /// Sets the address of the first byte of the value, line indices show this address plus the
+    /// offset of each line, wrapping around past `usize::MAX`; the default is zero.
+    pub fn with_base_address(mut self, base_address: usize) -> Self {
+        self.base_address = base_address;
+        self
+    }
+
+    /// Sets the offset of the first byte to dump, in the style of `xxd -s`. Where the first
+    /// address is not a multiple of the line length the first line is aligned with the columns
+    /// of the following lines, with leading cells left blank.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the maximum number of bytes to dump, in the style of `xxd -l`; the default is to dump
+    /// every byte from the offset.
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Sets the range of bytes to dump, equivalent to calling [`Self::with_offset`] and
+    /// [`Self::with_length`].
+    pub fn with_range(self, range: Range<usize>) -> Self {
+        let length = range.end.saturating_sub(range.start);
+        self.with_offset(range.start).with_length(length)
+    }
+
+    /// Adds an annotation, a named range of bytes. Where annotations overlap the bytes are
+    /// styled by the annotation with the shortest range, and of those the one added first, but
+    /// the labels of all of them are shown.
+    pub fn with_annotation(mut self, annotation: DumpAnnotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Adds an annotation of the bytes in `range` with the given label, see
+    /// [`Self::with_annotation`].
+    pub fn annotate<S: Into<String>>(self, range: Range<usize>, label: S) -> Self {
+        self.with_annotation(DumpAnnotation::new(range, label))
+    }
+
+    /// Use color to denote the kind of each byte, a [`ByteKind`], and
+    /// each part of the representation, styled by the [`Theme`] set with [`Self::with_theme`].
     #[cfg(feature = "repr-color")]
     pub fn use_color(mut self, colored: bool) -> Self {
-        self.colored = colored;
+        self.markup = Markup::from_color(colored);
+        self
+    }
+
+    /// Use color according to `policy`, which is resolved, for [`ColorPolicy::Auto`] by
+    /// checking the environment and stream, each time the representation is written.
+    #[cfg(feature = "repr-color")]
+    pub fn with_color_policy(mut self, policy: ColorPolicy) -> Self {
+        self.markup = Markup::from_policy(policy);
+        self
+    }
+
+    /// Sets the theme used to style the representation when color is used.
+    #[cfg(feature = "repr-color")]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
         self
     }
 
-    const fn byte_counts(&self) -> (usize, usize) {
-        match (self.two_columns, self.column_width) {
-            (false, w @ DumpColumnWidth::Eight) => (0, w.byte_count()),
-            (true, w @ DumpColumnWidth::Eight) => (w.byte_count(), w.two_column_byte_count()),
-            (false, w @ DumpColumnWidth::Sixteen) => (0, w.byte_count()),
-            (true, w @ DumpColumnWidth::Sixteen) => (w.byte_count(), w.two_column_byte_count()),
-            (false, w @ DumpColumnWidth::ThirtyTwo) => (0, w.byte_count()),
-            (true, w @ DumpColumnWidth::ThirtyTwo) => (w.byte_count(), w.two_column_byte_count()),
+    pub(crate) fn with_markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    #[cfg(all(feature = "std", feature = "repr-color"))]
+    pub(crate) fn for_terminal(mut self, is_terminal: bool) -> Self {
+        self.markup = self.markup.resolve_for(is_terminal);
+        self
+    }
+
+    ///
+    /// Returns the options with their markup resolved, see [`Markup::resolve`], cloning them
+    /// only where it changes.
+    ///
+    fn resolve_markup(&self) -> Cow<'_, Self> {
+        match self.markup.resolve() {
+            markup if markup == self.markup => Cow::Borrowed(self),
+            markup => Cow::Owned(self.clone().with_markup(markup)),
         }
     }
 
-    fn format_column_index(&self, index: usize) -> String {
-        let style = ReprComponentKind::Index.display_style(self.colored);
-        match self.radix_format {
-            RadixFormat::Binary => {
-                format!(
-                    "{style}{index:00$b}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+    ///
+    /// Returns the spans of the lines to dump, as [`Self::line_spans`], with `None` in place of
+    /// each run of squeezed lines.
+    ///
+    fn visible_line_spans(&self, value: &[u8]) -> Vec<Option<(usize, usize, Range<usize>)>> {
+        let (start, stop) = self.window(value.len());
+        let spans = self.line_spans(start, stop);
+        let last = spans.len().saturating_sub(1);
+        let mut visible = Vec::with_capacity(spans.len());
+        let mut previous: Option<&[u8]> = None;
+        let mut squeezed = false;
+        for (line, (line_index, leading, range)) in spans.into_iter().enumerate() {
+            let bytes = &value[range.clone()];
+            let full = leading + bytes.len() == self.bytes_per_line;
+            // The last line is always shown so that the extent of any squeezed lines is known.
+            if self.squeeze && full && line != last && previous == Some(bytes) {
+                if !squeezed {
+                    visible.push(None);
+                    squeezed = true;
+                }
+                continue;
             }
-            RadixFormat::Decimal => {
-                format!(
-                    "{style}{index:00$}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
-            }
-            RadixFormat::Octal => {
-                format!(
-                    "{style}{index:00$o}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
-            }
-            RadixFormat::LowerHex => {
-                format!(
-                    "{style}{index:00$x}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
-            }
-            RadixFormat::UpperHex => {
-                format!(
-                    "{style}{index:00$X}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+            previous = if full { Some(bytes) } else { None };
+            squeezed = false;
+            visible.push(Some((line_index, leading, range)));
+        }
+        visible
+    }
+
+    ///
+    /// Returns the start and end of the range of bytes to dump, from the offset and length,
+    /// limited to the length of the value.
+    ///
+    fn window(&self, value_length: usize) -> (usize, usize) {
+        let start = self.offset.min(value_length);
+        let stop = match self.length {
+            Some(length) => start.saturating_add(length).min(value_length),
+            None => value_length,
+        };
+        (start, stop)
+    }
+
+    ///
+    /// Returns the lines needed to dump the bytes from `start` to `stop`, each is the index of
+    /// the line, the number of leading blank cells, and the range of bytes on the line.
+    ///
+    fn line_spans(&self, start: usize, stop: usize) -> Vec<(usize, usize, Range<usize>)> {
+        let end = self.bytes_per_line;
+        let address = self.base_address.wrapping_add(start);
+        let mut line_index = address - address % end;
+        let mut leading = address % end;
+        let mut position = start;
+        let mut spans = Vec::with_capacity((stop - start) / end + 2);
+        while position < stop {
+            let next = (position + end - leading).min(stop);
+            spans.push((line_index, leading, position..next));
+            line_index = line_index.wrapping_add(end);
+            leading = 0;
+            position = next;
+        }
+        spans
+    }
+
+    ///
+    /// Returns `true` if the cell, a byte or word, at `index` on a line is the last of a group,
+    /// other than the last group, and so is followed by a column separator.
+    ///
+    const fn is_group_end(&self, index: usize) -> bool {
+        index + 1 != self.cells_per_line() && (index + 1).is_multiple_of(self.cell_group_size())
+    }
+
+    const fn is_single_bytes(&self) -> bool {
+        matches!(self.word_size, DumpWordSize::One)
+            && matches!(self.word_format, DumpWordFormat::Unsigned)
+    }
+
+    const fn cells_per_line(&self) -> usize {
+        self.bytes_per_line.div_ceil(self.word_size.byte_count())
+    }
+
+    const fn cell_group_size(&self) -> usize {
+        let group_size = self.group_size / self.word_size.byte_count();
+        if group_size == 0 {
+            1
+        } else {
+            group_size
+        }
+    }
+
+    ///
+    /// The number of cells used on a line with `leading` blank bytes followed by `length` bytes.
+    ///
+    const fn filled_cells(&self, leading: usize, length: usize) -> usize {
+        let word = self.word_size.byte_count();
+        leading / word + length.div_ceil(word)
+    }
+
+    fn write_column_index<W: Write + ?Sized>(&self, w: &mut W, index: usize) -> fmt::Result {
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Index, self.markup);
+        // Indices wrap at the largest value that fits a cell, so that the header stays aligned
+        // with the data on lines of more than 256 bytes.
+        let width = self.data_value_width();
+        let index = match u64::from(self.radix_format.radix()).checked_pow(width as u32) {
+            Some(limit) => index as u64 % limit,
+            None => index as u64,
+        };
+        write!(w, "{style}")?;
+        write_radix(w, self.radix_format, index, width)?;
+        write!(w, "{style:#}{}", self.markup.text(&self.value_spacing))
+    }
+
+    fn write_header_prefix<W: Write + ?Sized>(&self, w: &mut W, index_width: usize) -> fmt::Result {
+        write!(
+            w,
+            "{:1$}{2:3$}",
+            self.radix_format.prefix_str(),
+            index_width,
+            "",
+            self.line_index_spacing.len(),
+        )
+    }
+
+    fn write_header_indent<W: Write + ?Sized>(&self, w: &mut W, index_width: usize) -> fmt::Result {
+        write!(w, "{:1$}", "", index_width + self.line_index_spacing.len())
+    }
+
+    fn write_header_columns<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        for i in 0..self.cells_per_line() {
+            self.write_column_index(w, i * self.word_size.byte_count())?;
+            if self.is_group_end(i) {
+                self.write_column_separator(w)?;
             }
         }
+        Ok(())
     }
 
-    fn format_header_underline(&self) -> Option<String> {
-        if let Some(underline) = self.column_index_underline {
-            let width =
-                (self.data_value_width() + self.value_spacing.len()) * self.column_width as usize;
-            let style = ReprComponentKind::Separator.display_style(self.colored);
-            let underline = format!("{style}{}{style:#}", underline.to_string().repeat(width));
-            let mut buffer = String::default();
-            buffer.push_str(&underline);
-            if self.two_columns {
-                buffer.push_str(&self.format_column_separator());
-                buffer.push_str(&underline);
+    fn write_header_underline<W: Write + ?Sized>(&self, w: &mut W, underline: char) -> fmt::Result {
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Separator, self.markup);
+        let cell_width = self.data_value_width() + self.value_spacing.len();
+        let (cells, group_size) = (self.cells_per_line(), self.cell_group_size());
+        for start in (0..cells).step_by(group_size) {
+            if start > 0 {
+                self.write_column_separator(w)?;
             }
-            buffer.push('\n');
-            Some(buffer)
-        } else {
-            None
+            write!(w, "{style}")?;
+            for _ in 0..cell_width * group_size.min(cells - start) {
+                write!(w, "{}", self.markup.text(underline))?;
+            }
+            write!(w, "{style:#}")?;
         }
+        Ok(())
     }
 
-    fn format_column_separator(&self) -> String {
-        let style = ReprComponentKind::Separator.display_style(self.colored);
-        format!(
+    fn write_column_separator<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Separator, self.markup);
+        write!(
+            w,
             "{style}{}{}{style:#}",
-            self.column_separator, self.value_spacing
+            self.markup.text(self.column_separator),
+            self.markup.text(&self.value_spacing)
         )
     }
 
-    const fn line_index_width(&self) -> usize {
-        match self.index_radix_format {
+    ///
+    /// The width of the line index column for a value of `value_length` bytes, wide enough for
+    /// the highest line index in the window; where the addresses wrap past `usize::MAX` this is
+    /// the index of the last line before they wrap.
+    ///
+    fn line_index_width(&self, value_length: usize) -> usize {
+        let minimum = match self.index_radix_format {
             RadixFormat::Decimal | RadixFormat::Octal => 8,
             RadixFormat::LowerHex | RadixFormat::UpperHex => 6,
             _ => unreachable!(),
+        };
+        let (start, stop) = self.window(value_length);
+        let first = self.base_address.wrapping_add(start);
+        let last = self
+            .base_address
+            .wrapping_add(stop.saturating_sub(1).max(start));
+        let highest = if last < first { usize::MAX } else { last };
+        let line_index = highest - highest % self.bytes_per_line;
+        minimum.max(radix_digits(self.index_radix_format, line_index as u64))
+    }
+
+    fn write_line_index<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        index: usize,
+        index_width: usize,
+    ) -> fmt::Result {
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Index, self.markup);
+        write!(w, "{style}")?;
+        write_radix(w, self.index_radix_format, index as u64, index_width)?;
+        write!(w, "{}{style:#}", self.markup.text(&self.line_index_spacing))
+    }
+
+    const fn data_value_width(&self) -> usize {
+        let word = self.word_size.byte_count();
+        match (self.word_format, word) {
+            (DumpWordFormat::Float, 4) => 14,
+            (DumpWordFormat::Float, 8) => 24,
+            (DumpWordFormat::Signed, _) => word_digits(self.radix_format, word) + 1,
+            _ => word_digits(self.radix_format, word),
         }
     }
 
-    fn format_line_index(&self, index: usize) -> String {
-        let style = ReprComponentKind::Index.display_style(self.colored);
-        match self.index_radix_format {
-            RadixFormat::Decimal => format!(
-                "{style}{index:0width$}{spacer}{style:#}",
-                width = self.line_index_width(),
-                spacer = self.line_index_spacing
-            ),
-            RadixFormat::Octal => format!(
-                "{style}{index:0width$o}{spacer}{style:#}",
-                width = self.line_index_width(),
-                spacer = self.line_index_spacing
-            ),
-            RadixFormat::LowerHex => format!(
-                "{style}{index:0width$x}{spacer}{style:#}",
-                width = self.line_index_width(),
-                spacer = self.line_index_spacing
-            ),
-            RadixFormat::UpperHex => format!(
-                "{style}{index:0width$X}{spacer}{style:#}",
-                width = self.line_index_width(),
-                spacer = self.line_index_spacing
-            ),
-            _ => unreachable!(),
+    ///
+    /// Write a word, which may be shorter than the word size at the end of the value, as an
+    /// integer or float of the word format.
+    ///
+    fn write_word<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        bytes: &[u8],
+        offset: usize,
+        differs: bool,
+    ) -> fmt::Result {
+        let style = self.value_style(&bytes[0], offset, differs);
+        let value = match self.byte_order {
+            DumpByteOrder::BigEndian => bytes.iter().fold(0_u64, |v, b| (v << 8) | *b as u64),
+            DumpByteOrder::LittleEndian => {
+                bytes.iter().rev().fold(0_u64, |v, b| (v << 8) | *b as u64)
+            }
+        };
+        let bits = bytes.len() as u32 * 8;
+        let digits = word_digits(self.radix_format, bytes.len());
+        let width = self.data_value_width();
+        write!(w, "{style}")?;
+        match (self.word_format, bytes.len()) {
+            (DumpWordFormat::Float, 4) => write!(w, "{:>width$.7e}", f32::from_bits(value as u32))?,
+            (DumpWordFormat::Float, 8) => write!(w, "{:>width$.16e}", f64::from_bits(value))?,
+            (DumpWordFormat::Signed, _) if bits < 64 && (value >> (bits - 1)) & 1 == 1 => {
+                let magnitude = (1_u64 << bits) - value;
+                write!(
+                    w,
+                    "{:>1$}",
+                    '-',
+                    width - radix_digits(self.radix_format, magnitude)
+                )?;
+                write_radix(w, self.radix_format, magnitude, 0)?
+            }
+            (DumpWordFormat::Signed, _) if (value as i64) < 0 => {
+                let magnitude = (value as i64).unsigned_abs();
+                write!(
+                    w,
+                    "{:>1$}",
+                    '-',
+                    width - radix_digits(self.radix_format, magnitude)
+                )?;
+                write_radix(w, self.radix_format, magnitude, 0)?
+            }
+            (DumpWordFormat::Signed, _) => {
+                write!(
+                    w,
+                    "{:1$}",
+                    "",
+                    width - radix_digits(self.radix_format, value)
+                )?;
+                write_radix(w, self.radix_format, value, 0)?
+            }
+            _ => {
+                write!(w, "{:1$}", "", width.saturating_sub(digits))?;
+                write_radix(w, self.radix_format, value, digits)?
+            }
         }
+        write!(w, "{style:#}{}", self.markup.text(&self.value_spacing))
     }
 
-    const fn data_value_width(&self) -> usize {
-        match self.radix_format {
-            RadixFormat::Binary => 8,
-            RadixFormat::Decimal | RadixFormat::Octal => 3,
-            RadixFormat::LowerHex | RadixFormat::UpperHex => 2,
+    fn write_data_value<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        byte: u8,
+        offset: usize,
+        differs: bool,
+    ) -> fmt::Result {
+        let style = self.value_style(&byte, offset, differs);
+        style.write_start(w)?;
+        write_radix(w, self.radix_format, byte as u64, self.data_value_width())?;
+        style.write_end(w)?;
+        write!(w, "{}", self.markup.text(&self.value_spacing))
+    }
+
+    ///
+    /// Write a single byte as its character, `decoded`; whitespace characters, and bytes that
+    /// have no printable form, are shown as their value.
+    ///
+    fn write_ascii_char<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        byte: &u8,
+        decoded: DumpChar,
+        offset: usize,
+        differs: bool,
+    ) -> fmt::Result {
+        let style = self.value_style(byte, offset, differs);
+        match decoded {
+            DumpChar::Mapped(c) if !c.is_whitespace() => write!(
+                w,
+                "{style}{}{:2$}{style:#}{spacing}",
+                self.markup.text(c),
+                "",
+                self.data_value_width() - 1,
+                spacing = self.markup.text(&self.value_spacing)
+            ),
+            DumpChar::Continuation => self.write_blank_cell(w),
+            _ => write!(
+                w,
+                "{style}{byte:00$X}{style:#}{spacing}",
+                self.data_value_width(),
+                spacing = self.markup.text(&self.value_spacing)
+            ),
         }
     }
 
-    fn format_data_value(&self, byte: u8) -> String {
-        let style = ByteKind::ascii_char_display_style(&byte, self.colored);
-        match self.radix_format {
-            RadixFormat::Binary => {
-                format!(
-                    "{style}{byte:00$b}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+    ///
+    /// Write the values, and column separators, of a line; `leading` blank cells precede the
+    /// first byte which is at `position` in the value. Where `other`, the corresponding bytes of
+    /// another value, is provided any byte that differs from it is highlighted.
+    ///
+    fn write_values<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        bytes: &[u8],
+        leading: usize,
+        position: usize,
+        other: Option<&[u8]>,
+    ) -> fmt::Result {
+        let word = self.word_size.byte_count();
+        let leading = leading / word;
+        let chars = self.decode_line(bytes);
+        for index in 0..self.filled_cells(leading * word, bytes.len()) {
+            match index.checked_sub(leading).map(|i| i * word) {
+                None => self.write_blank_cell(w)?,
+                Some(i) => self.write_cell(w, bytes, &chars, i, position, other)?,
             }
-            RadixFormat::Decimal => {
-                format!(
-                    "{style}{byte:00$}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+            if self.is_group_end(index) {
+                self.write_column_separator(w)?;
             }
-            RadixFormat::Octal => {
-                format!(
-                    "{style}{byte:00$o}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+        }
+        Ok(())
+    }
+
+    ///
+    /// Write the cell holding the word that starts at `i` in the bytes of a line, `chars` are
+    /// the decoded characters of the line where these are shown.
+    ///
+    fn write_cell<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        bytes: &[u8],
+        chars: &[DumpChar],
+        i: usize,
+        position: usize,
+        other: Option<&[u8]>,
+    ) -> fmt::Result {
+        let word_bytes = &bytes[i..(i + self.word_size.byte_count()).min(bytes.len())];
+        let differs = word_bytes
+            .iter()
+            .enumerate()
+            .any(|(j, byte)| differs(other, i + j, byte));
+        if self.is_single_bytes() && self.show_ascii {
+            self.write_ascii_char(w, &bytes[i], chars[i], position + i, differs)
+        } else if self.is_single_bytes() {
+            self.write_data_value(w, bytes[i], position + i, differs)
+        } else {
+            self.write_word(w, word_bytes, position + i, differs)
+        }
+    }
+
+    fn decode_line(&self, bytes: &[u8]) -> Vec<DumpChar> {
+        if self.show_ascii {
+            self.decode_chars(bytes)
+        } else {
+            Vec::default()
+        }
+    }
+
+    ///
+    /// The divider between the two values on a line of a diff dump; in the header the divider is
+    /// preceded by space for any gutter so that it aligns with that on lines of values.
+    ///
+    fn write_diff_divider<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        differs: bool,
+        header: bool,
+    ) -> fmt::Result {
+        // Data values are followed by spacing, but the gutter is not.
+        let spacing = if self.ascii_gutter { " " } else { "" };
+        let gutter = if header && self.ascii_gutter {
+            self.bytes_per_line + 3
+        } else {
+            0
+        };
+        if differs {
+            let style = self
+                .theme
+                .display_style(ReprComponentKind::Difference, self.markup);
+            write!(w, "{:gutter$}{spacing}{style}≠{style:#} ", "")
+        } else {
+            let style = self
+                .theme
+                .display_style(ReprComponentKind::Separator, self.markup);
+            write!(w, "{:gutter$}{spacing}{style}│{style:#} ", "")
+        }
+    }
+
+    fn write_squeeze_marker<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Separator, self.markup);
+        writeln!(w, "{style}*{style:#}")
+    }
+
+    fn write_blank_cell<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        write!(
+            w,
+            "{:1$}",
+            "",
+            self.data_value_width() + self.value_spacing.chars().count()
+        )
+    }
+
+    ///
+    /// Pad a short line with blank cells, and any missing column separators, so that anything
+    /// following the values is aligned with that of full lines.
+    ///
+    fn write_line_padding<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        leading: usize,
+        length: usize,
+    ) -> fmt::Result {
+        let separator = 1 + self.value_spacing.chars().count();
+        for index in self.filled_cells(leading, length)..self.cells_per_line() {
+            self.write_blank_cell(w)?;
+            if self.is_group_end(index) {
+                write!(w, "{:separator$}", "")?;
             }
-            RadixFormat::LowerHex => {
-                format!(
-                    "{style}{byte:00$x}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+        }
+        Ok(())
+    }
+
+    fn write_ascii_gutter<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        bytes: &[u8],
+        leading: usize,
+        position: usize,
+        other: Option<&[u8]>,
+    ) -> fmt::Result {
+        // Leading blank cells are matched in the gutter.
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Delimiter, self.markup);
+        write!(w, " {style}|{style:#}{:leading$}", "")?;
+        self.write_gutter_chars(w, bytes, position, other)?;
+        write!(w, "{style}|{style:#}")
+    }
+
+    fn write_gutter_chars<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        bytes: &[u8],
+        position: usize,
+        other: Option<&[u8]>,
+    ) -> fmt::Result {
+        for ((i, byte), decoded) in bytes.iter().enumerate().zip(self.decode_chars(bytes)) {
+            let style = self.value_style(byte, position + i, differs(other, i, byte));
+            let c = match decoded {
+                DumpChar::Mapped(c) if c.is_whitespace() => ' ',
+                DumpChar::Mapped(c) => c,
+                DumpChar::Continuation => ' ',
+                DumpChar::Unmapped => self.replacement_char,
+            };
+            style.write_start(w)?;
+            write!(w, "{}", self.markup.text(c))?;
+            style.write_end(w)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns `true` if any annotation includes at least one byte in `range`.
+    ///
+    fn is_annotated(&self, range: Range<usize>) -> bool {
+        self.annotation_labels(range).next().is_some()
+    }
+
+    ///
+    /// Write the labels of every annotation that includes at least one byte in `range`,
+    /// following the annotation marker.
+    ///
+    fn write_annotation_labels<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        range: Range<usize>,
+    ) -> fmt::Result {
+        let style = self
+            .theme
+            .display_style(ReprComponentKind::Delimiter, self.markup);
+        // Data values are followed by spacing, but the gutter is not.
+        let spacing = if self.ascii_gutter { " " } else { "" };
+        write!(w, "{spacing}{style}{ANNOTATION_MARKER}{style:#}")?;
+        for (i, label) in self.annotation_labels(range).enumerate() {
+            if i > 0 {
+                w.write_str(", ")?;
             }
-            RadixFormat::UpperHex => {
-                format!(
-                    "{style}{byte:00$X}{style:#}{spacing}",
-                    self.data_value_width(),
-                    spacing = self.value_spacing
-                )
+            write!(w, "{label}")?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the styled labels of every annotation that includes at least one byte in `range`.
+    ///
+    fn annotation_labels(&self, range: Range<usize>) -> impl Iterator<Item = StyledLabel<'_>> {
+        self.annotations
+            .iter()
+            .enumerate()
+            .filter(move |(_, annotation)| {
+                annotation.range.start < range.end && range.start < annotation.range.end
+            })
+            .map(|(i, annotation)| StyledLabel {
+                style: self.annotation_style(i, &ByteKind::Printable),
+                markup: self.markup,
+                label: &annotation.label,
+            })
+    }
+
+    ///
+    /// Returns the index of the annotation used to style the byte at `offset`; where annotations
+    /// overlap the one with the shortest range is used, and of those the one added first.
+    ///
+    fn annotation_at(&self, offset: usize) -> Option<usize> {
+        self.annotations
+            .iter()
+            .enumerate()
+            .filter(|(_, annotation)| annotation.range.contains(&offset))
+            .min_by_key(|(_, annotation)| annotation.range.len())
+            .map(|(i, _)| i)
+    }
+
+    ///
+    /// The style of a byte, at `offset` in the value, that may differ from the corresponding
+    /// byte of another value it is compared with.
+    ///
+    fn value_style(&self, byte: &u8, offset: usize, differs: bool) -> Marker {
+        if differs {
+            return self
+                .theme
+                .display_style(ReprComponentKind::Difference, self.markup);
+        }
+        match self.annotation_at(offset) {
+            Some(i) => self.annotation_style(i, &ByteKind::byte_style(*byte)),
+            None => self.theme.byte_display_style(byte, self.markup),
+        }
+    }
+
+    ///
+    /// The style of an annotation is either the one it was given, or the style of the byte kind
+    /// with a background color chosen, in turn, from a fixed palette. In HTML the annotation
+    /// has a class of its own, also chosen in turn.
+    ///
+    #[cfg_attr(
+        not(any(feature = "repr-color", feature = "repr-html")),
+        allow(unused_variables)
+    )]
+    fn annotation_style(&self, index: usize, kind: &ByteKind) -> Marker {
+        match self.markup {
+            Markup::None => Marker::None,
+            // Automatic markup is resolved before writing, it is unstyled if it is not.
+            #[cfg(feature = "repr-color")]
+            Markup::Auto(_) => Marker::None,
+            #[cfg(feature = "repr-color")]
+            Markup::Ansi => Marker::Ansi(self.annotations[index].style.unwrap_or_else(|| {
+                self.theme.byte_kind_style(*kind).bg_color(Some(Color::Ansi(
+                    ANNOTATION_COLORS[index % ANNOTATION_COLORS.len()],
+                )))
+            })),
+            #[cfg(feature = "repr-html")]
+            Markup::Html => Marker::Html(ReprComponentKind::Value(*kind), Some(index)),
+        }
+    }
+
+    ///
+    /// Decode the bytes of a line, in the code page of the dump, into one character per byte.
+    ///
+    fn decode_chars(&self, bytes: &[u8]) -> Vec<DumpChar> {
+        let mut chars = Vec::with_capacity(bytes.len());
+        while chars.len() < bytes.len() {
+            let i = chars.len();
+            let length = match (self.code_page, bytes[i]) {
+                (DumpCodePage::Utf8, 0xC2..=0xDF) => 2,
+                (DumpCodePage::Utf8, 0xE0..=0xEF) => 3,
+                (DumpCodePage::Utf8, 0xF0..=0xF4) => 4,
+                _ => 1,
+            };
+            let decoded = if length == 1 {
+                self.decode_char(&bytes[i])
+            } else {
+                bytes
+                    .get(i..i + length)
+                    .and_then(|bytes| core::str::from_utf8(bytes).ok())
+                    .and_then(|s| s.chars().next())
+            };
+            match decoded.filter(|c| !c.is_control()) {
+                Some(c) => {
+                    chars.push(DumpChar::Mapped(c));
+                    chars.extend((1..length).map(|_| DumpChar::Continuation));
+                }
+                None => chars.push(DumpChar::Unmapped),
             }
         }
+        chars
     }
 
-    fn format_ascii_char(&self, byte: &u8) -> String {
+    fn decode_char(&self, byte: &u8) -> Option<char> {
+        let table_char = |table: &[char; 256]| Some(table[*byte as usize]).filter(|c| *c != '\0');
+        match self.code_page {
+            DumpCodePage::Latin1 => self.decode_latin1_char(byte),
+            DumpCodePage::Cp437 => table_char(&CP437_CHARS),
+            DumpCodePage::Cp037 => table_char(&CP037_CHARS),
+            DumpCodePage::Utf8 if byte.is_ascii() => self.decode_latin1_char(byte),
+            DumpCodePage::Utf8 => None,
+            DumpCodePage::Custom(mapping) => mapping(*byte),
+        }
+    }
+
+    fn decode_latin1_char(&self, byte: &u8) -> Option<char> {
         // This follows ISO 8859-1.
-        let decoded_char = match byte {
+        match byte {
             // 7-bit ASCII control characters
             0x00 if self.show_extended_ascii => Some('␀'),
             0x01 if self.show_extended_ascii => Some('␁'),
@@ -603,6 +1840,7 @@ impl DumpFormatOptions {
             0x1E if self.show_extended_ascii => Some('␞'),
             0x1F if self.show_extended_ascii => Some('␟'),
             0x20 if self.show_extended_ascii => Some('␠'),
+            0x20 => Some(' '),
             // Printable 7-bit ASCII characters.
             0x21..=0x7E => Some(*byte as char),
             // 7-bit ASCII control character
@@ -614,22 +1852,563 @@ impl DumpFormatOptions {
             // Printable 8-bit ASCII characters.
             0xA1..=0xAC | 0xAE..=0xFF => Some(*byte as char),
             _ => None, // Non-printable characters
-        };
-        let style = ByteKind::ascii_char_display_style(byte, self.colored);
-        if let Some(c) = decoded_char {
-            format!(
-                "{style}{c:0$}{style:#}{spacing}",
-                self.data_value_width(),
-                spacing = self.value_spacing
-            )
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The layout of a dump, as far as it can be determined from the header line, used when parsing.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct DumpLayout {
+    radix_format: RadixFormat,
+    line_length: Option<usize>,
+    separator: Option<String>,
+    separator_positions: Vec<usize>,
+}
+
+///
+/// A byte decoded as part of a line of characters.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DumpChar {
+    /// The byte, with any bytes following it, is a printable character.
+    Mapped(char),
+    /// The byte is part of a multi-byte character that started before it.
+    Continuation,
+    /// The byte has no printable form.
+    Unmapped,
+}
+
+///
+/// The label of an annotation, displayed in the style of the annotation and escaped as the markup
+/// requires.
+///
+struct StyledLabel<'a> {
+    style: Marker,
+    markup: Markup,
+    label: &'a str,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const ANNOTATION_MARKER: &str = " ◂ ";
+
+// Code page tables, `'\0'` marks a byte that has no printable form.
+#[rustfmt::skip]
+const CP437_CHARS: [char; 256] = [
+    '\0', '☺', '☻', '♥', '♦', '♣', '♠', '•',
+    '◘', '○', '◙', '♂', '♀', '♪', '♫', '☼',
+    '►', '◄', '↕', '‼', '¶', '§', '▬', '↨',
+    '↑', '↓', '→', '←', '∟', '↔', '▲', '▼',
+    ' ', '!', '"', '#', '$', '%', '&', '\'',
+    '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7',
+    '8', '9', ':', ';', '<', '=', '>', '?',
+    '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
+    'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W',
+    'X', 'Y', 'Z', '[', '\\', ']', '^', '_',
+    '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+    'x', 'y', 'z', '{', '|', '}', '~', '⌂',
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
+    'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù',
+    'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ',
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
+    '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
+    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
+    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
+    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
+    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
+    '°', '∙', '·', '√', 'ⁿ', '²', '■', '\0',
+];
+
+#[rustfmt::skip]
+const CP037_CHARS: [char; 256] = [
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    '\0', '\0', '\0', '\0', '\0', '\0', '\0', '\0',
+    ' ', '\0', 'â', 'ä', 'à', 'á', 'ã', 'å',
+    'ç', 'ñ', '¢', '.', '<', '(', '+', '|',
+    '&', 'é', 'ê', 'ë', 'è', 'í', 'î', 'ï',
+    'ì', 'ß', '!', '$', '*', ')', ';', '¬',
+    '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å',
+    'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?',
+    'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï',
+    'Ì', '`', ':', '#', '@', '\'', '=', '"',
+    'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+    'h', 'i', '«', '»', 'ð', 'ý', 'þ', '±',
+    '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p',
+    'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤',
+    'µ', '~', 's', 't', 'u', 'v', 'w', 'x',
+    'y', 'z', '¡', '¿', 'Ð', 'Ý', 'Þ', '®',
+    '^', '£', '¥', '·', '©', '§', '¶', '¼',
+    '½', '¾', '[', ']', '¯', '¨', '´', '×',
+    '{', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
+    'H', 'I', '\0', 'ô', 'ö', 'ò', 'ó', 'õ',
+    '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P',
+    'Q', 'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ',
+    '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X',
+    'Y', 'Z', '²', 'Ô', 'Ö', 'Ò', 'Ó', 'Õ',
+    '0', '1', '2', '3', '4', '5', '6', '7',
+    '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú', '\0',
+];
+
+#[cfg(feature = "repr-color")]
+const ANNOTATION_COLORS: [AnsiColor; 6] = [
+    AnsiColor::Blue,
+    AnsiColor::Magenta,
+    AnsiColor::Cyan,
+    AnsiColor::Yellow,
+    AnsiColor::Red,
+    AnsiColor::Green,
+];
+
+fn strip_ansi_escapes(line: &str) -> Cow<'_, str> {
+    if !line.contains('\u{1b}') {
+        return Cow::Borrowed(line);
+    }
+    let mut buffer = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip the Control Sequence Introducer and everything up to and including the final
+            // byte, which is in the range '@'..='~'.
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
         } else {
-            format!(
-                "{style}{byte:00$X}{style:#}{spacing}",
-                self.data_value_width(),
-                spacing = self.value_spacing
+            buffer.push(c);
+        }
+    }
+    Cow::Owned(buffer)
+}
+
+///
+/// Locate an error, located in `line`, in `s`. If ANSI escape sequences were stripped from the
+/// `original` line offsets in `line` do not match those in `s`, so the whole line is located.
+///
+fn locate_in_line(s: &str, original: &str, line: &str, error: ParseError) -> ParseError {
+    if line.len() == original.len() {
+        error.offset_by(s, offset_in(s, original))
+    } else {
+        let expected = error.expected();
+        error
+            .into_kind()
+            .at(s, offset_in(s, original), original.len(), expected)
+    }
+}
+
+fn is_data_line(line: &str) -> bool {
+    line.split_whitespace()
+        .next()
+        .and_then(|token| token.strip_suffix(':'))
+        .map(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_hexdigit()))
+        .unwrap_or_default()
+}
+
+fn line_index_values(index: &str) -> impl Iterator<Item = usize> + '_ {
+    // The width of the line index is determined by it's radix, hex indices are at least six
+    // characters wide whereas decimal and octal indices are both at least eight characters wide;
+    // all are widened to fit the highest index, so a wide index may be in any radix.
+    let radixes: &[u32] = if index.len() < 8 { &[16] } else { &[10, 8, 16] };
+    radixes
+        .iter()
+        .filter_map(move |radix| usize::from_str_radix(index, *radix).ok())
+}
+
+fn line_index_matches(index: &str, expected: usize) -> bool {
+    line_index_values(index).any(|value| value == expected)
+}
+
+///
+/// The number of lines replaced by a squeeze marker, given the index of the line following it.
+///
+fn squeezed_line_count(index: &str, expected: usize, line_length: usize) -> Option<usize> {
+    line_index_values(index)
+        .filter(|value| *value > expected && (value - expected).is_multiple_of(line_length))
+        .map(|value| (value - expected) / line_length)
+        .next()
+}
+
+fn detect_index_radix(
+    indices: &[&str],
+    line_length: usize,
+    byte_radix: RadixFormat,
+) -> RadixFormat {
+    match indices.get(1) {
+        Some(index) if index.len() < 8 => {
+            if byte_radix == RadixFormat::LowerHex
+                || indices
+                    .iter()
+                    .any(|index| index.contains(char::is_lowercase))
+            {
+                RadixFormat::LowerHex
+            } else {
+                RadixFormat::UpperHex
+            }
+        }
+        Some(index) if usize::from_str_radix(index, 8) == Ok(line_length) => RadixFormat::Octal,
+        Some(_) => RadixFormat::Decimal,
+        None if byte_radix == RadixFormat::LowerHex => RadixFormat::LowerHex,
+        None => RadixFormat::UpperHex,
+    }
+}
+
+///
+/// Parse the data values of a line, following the line index, ignoring any annotation labels.
+/// A trailing ASCII gutter is recognized as a `|` preceded by two spaces where the values before
+/// it parse and their number matches the number of characters in the gutter; as the gutter, and
+/// values shown as ASCII, may contain `|` each such position is tried in turn.
+///
+fn parse_line_values(line: &str, data: &str, layout: &DumpLayout) -> Result<Vec<u8>, ParseError> {
+    let data = data
+        .split_once(ANNOTATION_MARKER)
+        .map_or(data, |(data, _)| data);
+    let trimmed = data.trim_end();
+    if trimmed.ends_with('|') {
+        for (start, _) in trimmed.match_indices("  |") {
+            if let (Some(gutter), Ok(values)) = (
+                trimmed.get(start + 3..trimmed.len() - 1),
+                parse_data_values(line, &trimmed[..start], layout),
+            ) {
+                if values.len() == gutter.chars().count() {
+                    return Ok(values);
+                }
+            }
+        }
+    }
+    parse_data_values(line, data, layout)
+}
+
+///
+/// Parse the data values, `data`, which is a slice of `line`; any error is located in `line`.
+///
+fn parse_data_values(line: &str, data: &str, layout: &DumpLayout) -> Result<Vec<u8>, ParseError> {
+    let width = layout.radix_format.max_width();
+    let mut values = Vec::default();
+    let mut last_separator = None;
+    for token in data.split_whitespace() {
+        if last_separator != Some(values.len()) && layout.is_separator(token, values.len()) {
+            last_separator = Some(values.len());
+            continue;
+        }
+        let mut chars = token.chars();
+        let value = match (chars.next(), chars.next()) {
+            (Some(c), None) if width > 1 => parse_ascii_char(c).ok_or_else(|| {
+                Error::InvalidRepresentation.at(
+                    line,
+                    offset_in(line, token),
+                    token.len(),
+                    Some("a byte value or a Latin-1 character"),
+                )
+            })?,
+            _ if token.len() == width => layout.radix_format.parse_byte(line, token)?,
+            _ => {
+                return Err(Error::InvalidRepresentation.at(
+                    line,
+                    offset_in(line, token),
+                    token.len(),
+                    Some(layout.radix_format.expected_byte()),
+                ))
+            }
+        };
+        values.push(value);
+    }
+    Ok(values)
+}
+
+///
+/// The number of digits needed for the largest unsigned integer of `word` bytes.
+///
+const fn word_digits(radix_format: RadixFormat, word: usize) -> usize {
+    match radix_format {
+        RadixFormat::Binary => word * 8,
+        RadixFormat::Octal => (word * 8).div_ceil(3),
+        RadixFormat::Decimal => (u64::MAX >> (64 - word * 8)).ilog10() as usize + 1,
+        RadixFormat::LowerHex | RadixFormat::UpperHex => word * 2,
+    }
+}
+
+fn write_radix<W: Write + ?Sized>(
+    w: &mut W,
+    radix_format: RadixFormat,
+    value: u64,
+    width: usize,
+) -> fmt::Result {
+    if let Ok(byte) = u8::try_from(value) {
+        if width == radix_format.max_width() {
+            return radix_format.write(w, &byte, false);
+        }
+    }
+    match radix_format {
+        RadixFormat::Binary => write!(w, "{value:00$b}", width),
+        RadixFormat::Octal => write!(w, "{value:00$o}", width),
+        RadixFormat::Decimal => write!(w, "{value:00$}", width),
+        RadixFormat::LowerHex => write!(w, "{value:00$x}", width),
+        RadixFormat::UpperHex => write!(w, "{value:00$X}", width),
+    }
+}
+
+///
+/// The number of digits of `value`, without leading zeros, in the radix.
+///
+fn radix_digits(radix_format: RadixFormat, value: u64) -> usize {
+    let radix = u64::from(radix_format.radix());
+    let mut digits = 1;
+    let mut remaining = value / radix;
+    while remaining > 0 {
+        digits += 1;
+        remaining /= radix;
+    }
+    digits
+}
+
+///
+/// Returns `true` if `other` is provided and the byte at `index` in it is not `byte`.
+///
+fn differs(other: Option<&[u8]>, index: usize, byte: &u8) -> bool {
+    other.is_some_and(|other| other.get(index) != Some(byte))
+}
+
+fn parse_ascii_char(c: char) -> Option<u8> {
+    // This is the inverse of `DumpFormatOptions::decode_latin1_char`, spaces are never shown.
+    match c {
+        '\u{2400}'..='\u{2420}' => u8::try_from(c as u32 - 0x2400).ok(),
+        '␡' => Some(0x7F),
+        '⍽' => Some(0xA0),
+        _ => u8::try_from(c as u32).ok(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations > DumpLayout
+// ------------------------------------------------------------------------------------------------
+
+impl DumpLayout {
+    fn from_header(line: &str) -> Result<Self, ParseError> {
+        let mut tokens = line.split_whitespace();
+        let prefix = tokens.next().ok_or_else(|| {
+            Error::InvalidRepresentation.at(line, 0, line.len(), Some(EXPECTED_RADIX_PREFIX))
+        })?;
+        let invalid = |error: Error| {
+            error.at(
+                line,
+                offset_in(line, prefix),
+                prefix.len(),
+                Some(EXPECTED_RADIX_PREFIX),
             )
+        };
+        let radix_char = prefix
+            .strip_prefix('0')
+            .ok_or_else(|| invalid(Error::MissingRadixPrefix))?
+            .chars()
+            .next();
+        if prefix.len() != 2 {
+            return Err(invalid(Error::InvalidRadixPrefix));
+        }
+        let radix_format =
+            RadixFormat::from(radix_char).map_err(|_| invalid(Error::InvalidRadixPrefix))?;
+        let radix = radix_format.radix();
+
+        let mut layout = Self {
+            radix_format,
+            ..Default::default()
+        };
+        let mut line_length = 0;
+        for token in tokens {
+            if usize::from_str_radix(token, radix).is_ok() {
+                line_length += 1;
+            } else {
+                layout.separator = Some(token.to_string());
+                layout.separator_positions.push(line_length);
+            }
+        }
+        layout.line_length = Some(line_length);
+        Ok(layout)
+    }
+
+    fn is_separator(&self, token: &str, position: usize) -> bool {
+        match &self.separator {
+            Some(separator) => token == separator && self.separator_positions.contains(&position),
+            None if self.line_length.is_none() => {
+                let mut chars = token.chars();
+                matches!(
+                    (chars.next(), chars.next()),
+                    (Some(c), None) if !c.is_alphanumeric()
+                )
+            }
+            None => false,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations > DumpDiffOptions
+// ------------------------------------------------------------------------------------------------
+
+impl From<DumpFormatOptions> for DumpDiffOptions {
+    fn from(layout: DumpFormatOptions) -> Self {
+        Self {
+            layout,
+            context: None,
+        }
+    }
+}
+
+impl DumpDiffOptions {
+    /// Sets the dump options used to lay out each line.
+    pub fn with_layout(mut self, layout: DumpFormatOptions) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Show only the lines that differ, and `context` lines either side of them; runs of lines
+    /// that are not shown are replaced by a single line containing `*`.
+    pub fn only_differing_lines(mut self, context: usize) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Show every line, this is the default.
+    pub fn all_lines(mut self) -> Self {
+        self.context = None;
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations > DumpDiffSummary
+// ------------------------------------------------------------------------------------------------
+
+impl Display for DumpDiffSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.first_difference {
+            None => write!(f, "No differences, {} bytes.", self.left_length),
+            Some(first) => {
+                let plural = if self.differing_bytes == 1 { "" } else { "s" };
+                write!(
+                    f,
+                    "{} differing byte{plural}, the first at offset {first}",
+                    self.differing_bytes
+                )?;
+                if self.has_length_mismatch() {
+                    write!(
+                        f,
+                        "; lengths differ, {} and {} bytes",
+                        self.left_length, self.right_length
+                    )?;
+                }
+                write!(f, ".")
+            }
+        }
+    }
+}
+
+impl DumpDiffSummary {
+    /// The number of bytes that differ, including those beyond the end of the shorter value.
+    pub const fn differing_bytes(&self) -> usize {
+        self.differing_bytes
+    }
+
+    /// The offset of the first byte that differs, if any.
+    pub const fn first_difference(&self) -> Option<usize> {
+        self.first_difference
+    }
+
+    pub const fn left_length(&self) -> usize {
+        self.left_length
+    }
+
+    pub const fn right_length(&self) -> usize {
+        self.right_length
+    }
+
+    pub const fn is_identical(&self) -> bool {
+        self.first_difference.is_none()
+    }
+
+    pub const fn has_length_mismatch(&self) -> bool {
+        self.left_length != self.right_length
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations > StyledLabel
+// ------------------------------------------------------------------------------------------------
+
+impl Display for StyledLabel<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let style = self.style;
+        write!(f, "{style}{}{style:#}", self.markup.text(self.label))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations > DumpAnnotation
+// ------------------------------------------------------------------------------------------------
+
+impl DumpAnnotation {
+    /// Create an annotation of the bytes in `range`, offsets in the value being dumped, with the
+    /// given label.
+    pub fn new<S: Into<String>>(range: Range<usize>, label: S) -> Self {
+        Self {
+            range,
+            label: label.into(),
+            #[cfg(feature = "repr-color")]
+            style: None,
         }
     }
+
+    /// Sets the style used for the bytes and label of this annotation, replacing the default
+    /// which is the style of each byte on a background color chosen in turn from a palette.
+    #[cfg(feature = "repr-color")]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations > DumpWordSize
+// ------------------------------------------------------------------------------------------------
+
+impl DumpWordSize {
+    #[inline(always)]
+    pub const fn byte_count(&self) -> usize {
+        *self as usize
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -637,6 +2416,16 @@ impl DumpFormatOptions {
 // ------------------------------------------------------------------------------------------------
 
 impl DumpColumnWidth {
+    /// Return the column width with the given number of bytes, if there is one.
+    pub const fn from_byte_count(byte_count: usize) -> Option<Self> {
+        match byte_count {
+            8 => Some(Self::Eight),
+            16 => Some(Self::Sixteen),
+            32 => Some(Self::ThirtyTwo),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub const fn byte_count(&self) -> usize {
         *self as usize
@@ -0,0 +1,418 @@
+//!
+//! An escaped string representation of binary data, in the style of a Rust byte string literal
+//! or a C string literal. Printable ASCII characters, as classified by [`ByteKind`], and the
+//! space character are written as themselves and all other bytes as escapes.
+//!
+//! ```ebnf
+//! EscapedRepresentation ::= RustEscapedRepr | CEscapedRepr
+//!
+//! RustEscapedRepr ::= 'b"' { Printable | SimpleEscape | HexEscape } '"'
+//! CEscapedRepr ::= '"' { Printable | SimpleEscape | HexEscape | OctalEscape } '"'
+//!
+//! SimpleEscape ::= '\n' | '\r' | '\t' | '\0' | '\\' | '\"' | "\'"
+//! HexEscape ::= '\x' Nybble Nybble
+//! OctalEscape ::= '\' Octal [ Octal [ Octal ] ]
+//! ```
+//!
+//! As a C hex escape continues for as many hex digits as follow it, a hex digit character after a
+//! hex escape is written as an octal escape; similarly a `\0` followed by an octal digit character
+//! is written as `\000`.
+//!
+//! When parsing the C dialect the additional escapes `\a`, `\b`, `\f`, `\v`, and `\?` are
+//! accepted, hex escapes may have any number of digits as long as the value fits in a byte, and
+//! adjacent string literals separated by whitespace are concatenated.
+//!
+//! # Examples
+//!
+#![cfg_attr(
+    any(
+        not(feature = "repr-escaped"),
+        all(feature = "repr-escaped", feature = "repr-color")
+    ),
+    doc = "```ignore"
+)]
+#![cfg_attr(
+    all(feature = "repr-escaped", not(feature = "repr-color")),
+    doc = "```rust"
+)]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, escaped::EscapedFormatOptions, format}
+//! };
+//!
+//! let binary = Binary::from(b"GET / HTTP/1.1\r\n\r\n\x00\xff\"");
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         EscapedFormatOptions::default()),
+//!     r#"b"GET / HTTP/1.1\r\n\r\n\0\xff\"""#.to_string(),
+//! );
+//!
+//! let binary = Binary::from(b"\xff0\x001");
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         EscapedFormatOptions::default().with_c_dialect()),
+//!     r#""\xff\060\0001""#.to_string(),
+//! );
+//! ```
+//!
+
+#[cfg(feature = "repr-color")]
+use crate::repr::color::ColorPolicy;
+use crate::{
+    error::{offset_in, Error, ParseError},
+    repr::{color::Theme, format_with, BinaryFormatOptions, ByteKind, Markup, ReprComponentKind},
+    Binary,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    matches,
+    option::Option::{self, None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EscapedFormatOptions {
+    dialect: EscapeDialect,
+    theme: Theme,
+    markup: Markup,
+}
+
+///
+/// The language whose string literal syntax is used.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EscapeDialect {
+    /// A Rust byte string literal, `b"..."`.
+    #[default]
+    Rust,
+    /// A C string literal, `"..."`.
+    C,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn escaped_representation(value: &Binary<'_>, options: &EscapedFormatOptions) -> String {
+    format_with(value.len() + 3, |buffer| {
+        write_escaped_representation(buffer, value, options)
+    })
+}
+
+///
+/// Write the escaped string representation of `value` to `w`, see [`escaped_representation`].
+///
+pub fn write_escaped_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &EscapedFormatOptions,
+) -> fmt::Result {
+    let prefix = match options.dialect {
+        EscapeDialect::Rust => "b",
+        EscapeDialect::C => "",
+    };
+    let markup = options.markup.resolve();
+    let prefix_style = options
+        .theme
+        .display_style(ReprComponentKind::Prefix, markup);
+    let quote = options
+        .theme
+        .display_style(ReprComponentKind::Delimiter, markup);
+
+    let bytes = value.as_ref();
+    write!(
+        w,
+        "{prefix_style}{prefix}{prefix_style:#}{quote}{}{quote:#}",
+        markup.text('"')
+    )?;
+    for (i, byte) in bytes.iter().enumerate() {
+        let style = options.theme.byte_display_style(byte, markup);
+        style.write_start(w)?;
+        options.write_escaped_byte(
+            w,
+            *byte,
+            i.checked_sub(1).map(|i| bytes[i]),
+            bytes.get(i + 1),
+        )?;
+        style.write_end(w)?;
+    }
+    write!(w, "{quote}{}{quote:#}", markup.text('"'))
+}
+
+///
+/// Parse an escaped string representation, the dialect is determined by the presence of the
+/// `b` prefix of a Rust byte string.
+///
+pub fn parse_escaped_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    let (dialect, start) = if s.starts_with("b\"") {
+        (EscapeDialect::Rust, 1)
+    } else {
+        (EscapeDialect::C, 0)
+    };
+    let mut values = Vec::with_capacity(s.len());
+    let mut rest = &s[start..];
+    loop {
+        let offset = s.len() - rest.len();
+        if !rest.starts_with('"') {
+            return Err(Error::InvalidStringQuotes.at(s, offset, 1, Some(EXPECTED_QUOTE)));
+        }
+        let length = parse_literal(s, offset + 1, dialect, &mut values)?;
+        rest = &s[offset + length + 2..];
+        let trimmed = rest.trim_start();
+        if trimmed.is_empty() {
+            break;
+        } else if dialect == EscapeDialect::Rust {
+            return Err(Error::InvalidStringQuotes.at(
+                s,
+                offset_in(s, trimmed),
+                1,
+                Some("the end of input"),
+            ));
+        }
+        rest = trimmed;
+    }
+    Ok(Binary::from(values))
+}
+
+///
+/// Detect the options used to produce an escaped string representation, returns `None` unless
+/// the string is enclosed in double quotes, with an optional `b` prefix.
+///
+pub(crate) fn detect_escaped_options(s: &str) -> Option<EscapedFormatOptions> {
+    if s.len() < 2 || !s.ends_with('"') {
+        None
+    } else if s.starts_with("b\"") {
+        Some(EscapedFormatOptions::default().with_rust_dialect())
+    } else if s.starts_with('"') {
+        Some(EscapedFormatOptions::default().with_c_dialect())
+    } else {
+        None
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const EXPECTED_QUOTE: &str = "`\"`";
+
+///
+/// Parse the content of a single literal, starting at `start` in `s` following its opening
+/// quote, into `values`; returns the length of the content, the closing quote follows it.
+///
+fn parse_literal(
+    s: &str,
+    start: usize,
+    dialect: EscapeDialect,
+    values: &mut Vec<u8>,
+) -> Result<usize, ParseError> {
+    let mut chars = s[start..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let offset = start + i;
+        match c {
+            '"' => return Ok(i),
+            '\\' => {
+                let Some((_, escape)) = chars.next() else {
+                    return Err(Error::InvalidStringQuotes.at(s, s.len(), 0, Some(EXPECTED_QUOTE)));
+                };
+                let value = match (dialect, escape) {
+                    (_, 'n') => Some(b'\n'),
+                    (_, 'r') => Some(b'\r'),
+                    (_, 't') => Some(b'\t'),
+                    (_, '\\') => Some(b'\\'),
+                    (_, '"') => Some(b'"'),
+                    (_, '\'') => Some(b'\''),
+                    (EscapeDialect::Rust, '0') => Some(b'\0'),
+                    (EscapeDialect::C, 'a') => Some(0x07),
+                    (EscapeDialect::C, 'b') => Some(0x08),
+                    (EscapeDialect::C, 'f') => Some(0x0C),
+                    (EscapeDialect::C, 'v') => Some(0x0B),
+                    (EscapeDialect::C, '?') => Some(b'?'),
+                    (_, 'x') => {
+                        let max_digits = match dialect {
+                            EscapeDialect::Rust => 2,
+                            EscapeDialect::C => usize::MAX,
+                        };
+                        let digits = take_digits(&mut chars, 16, max_digits);
+                        if digits.is_empty()
+                            || (dialect == EscapeDialect::Rust && digits.len() != 2)
+                        {
+                            None
+                        } else {
+                            digits_value(&digits, 16)
+                        }
+                    }
+                    (EscapeDialect::C, '0'..='7') => {
+                        let mut digits = Vec::with_capacity(3);
+                        digits.push(escape);
+                        digits.extend(take_digits(&mut chars, 8, 2));
+                        digits_value(&digits, 8)
+                    }
+                    _ => None,
+                };
+                let Some(value) = value else {
+                    let end = chars.peek().map_or(s.len(), |(j, _)| start + j);
+                    return Err(Error::InvalidEncoding.at(
+                        s,
+                        offset,
+                        end - offset,
+                        Some("an escape sequence"),
+                    ));
+                };
+                values.push(value);
+            }
+            c if c.is_ascii() && !c.is_ascii_control() => values.push(c as u8),
+            c if dialect == EscapeDialect::C && !c.is_ascii() => {
+                let mut buffer = [0; 4];
+                values.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            c => {
+                return Err(Error::InvalidEncoding.at(
+                    s,
+                    offset,
+                    c.len_utf8(),
+                    Some("a printable ASCII character or an escape sequence"),
+                ))
+            }
+        }
+    }
+    Err(Error::InvalidStringQuotes.at(s, s.len(), 0, Some(EXPECTED_QUOTE)))
+}
+
+fn take_digits<I>(chars: &mut core::iter::Peekable<I>, radix: u32, max_digits: usize) -> Vec<char>
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    let mut digits = Vec::new();
+    while digits.len() < max_digits {
+        match chars.peek() {
+            Some((_, c)) if c.is_digit(radix) => {
+                digits.push(*c);
+                chars.next();
+            }
+            _ => break,
+        }
+    }
+    digits
+}
+
+fn digits_value(digits: &[char], radix: u32) -> Option<u8> {
+    digits.iter().try_fold(0_u8, |value, c| {
+        value
+            .checked_mul(radix as u8)?
+            .checked_add(c.to_digit(radix)? as u8)
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<EscapedFormatOptions> for BinaryFormatOptions {
+    fn from(value: EscapedFormatOptions) -> Self {
+        Self::Escaped(value)
+    }
+}
+
+impl EscapedFormatOptions {
+    /// Sets the string literal syntax to be one of the values of the enum [`EscapeDialect`].
+    pub fn with_dialect(mut self, dialect: EscapeDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+    /// Sets the string literal syntax to [`EscapeDialect::Rust`].
+    pub fn with_rust_dialect(self) -> Self {
+        Self::with_dialect(self, EscapeDialect::Rust)
+    }
+    /// Sets the string literal syntax to [`EscapeDialect::C`].
+    pub fn with_c_dialect(self) -> Self {
+        Self::with_dialect(self, EscapeDialect::C)
+    }
+
+    /// Use color to denote the kind of each byte, a [`ByteKind`], and
+    /// each part of the representation, styled by the [`Theme`] set with [`Self::with_theme`].
+    #[cfg(feature = "repr-color")]
+    pub fn use_color(mut self, colored: bool) -> Self {
+        self.markup = Markup::from_color(colored);
+        self
+    }
+
+    /// Use color according to `policy`, which is resolved, for [`ColorPolicy::Auto`] by
+    /// checking the environment and stream, each time the representation is written.
+    #[cfg(feature = "repr-color")]
+    pub fn with_color_policy(mut self, policy: ColorPolicy) -> Self {
+        self.markup = Markup::from_policy(policy);
+        self
+    }
+
+    /// Sets the theme used to style the representation when color is used.
+    #[cfg(feature = "repr-color")]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    #[cfg(any(feature = "repr-html", feature = "repr-markdown"))]
+    pub(crate) fn with_markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    #[cfg(all(feature = "std", feature = "repr-color"))]
+    pub(crate) fn for_terminal(mut self, is_terminal: bool) -> Self {
+        self.markup = self.markup.resolve_for(is_terminal);
+        self
+    }
+
+    fn write_escaped_byte<W: Write + ?Sized>(
+        &self,
+        w: &mut W,
+        byte: u8,
+        previous: Option<u8>,
+        next: Option<&u8>,
+    ) -> fmt::Result {
+        let is_c = self.dialect == EscapeDialect::C;
+        match byte {
+            b'\n' => w.write_str("\\n"),
+            b'\r' => w.write_str("\\r"),
+            b'\t' => w.write_str("\\t"),
+            b'\\' => w.write_str("\\\\"),
+            b'"' => write!(w, "{}", self.markup.text("\\\"")),
+            b'\0' if is_c && next.is_some_and(|next| (b'0'..=b'7').contains(next)) => {
+                w.write_str("\\000")
+            }
+            b'\0' => w.write_str("\\0"),
+            b' ' => w.write_char(' '),
+            // A hex digit following a hex escape would extend the escape in C.
+            _ if is_c
+                && byte.is_ascii_hexdigit()
+                && previous.is_some_and(|previous| self.is_hex_escaped(previous)) =>
+            {
+                write!(w, "\\{byte:03o}")
+            }
+            _ if ByteKind::from_byte(byte) == ByteKind::Printable => {
+                write!(w, "{}", self.markup.text(byte as char))
+            }
+            _ => write!(w, "\\x{byte:02x}"),
+        }
+    }
+
+    fn is_hex_escaped(&self, byte: u8) -> bool {
+        !matches!(byte, b'\n' | b'\r' | b'\t' | b'\\' | b'"' | b'\0' | b' ')
+            && ByteKind::from_byte(byte) != ByteKind::Printable
+    }
+}
@@ -0,0 +1,176 @@
+//!
+//! Render a representation as HTML, for publishing in web pages. The representation is laid out
+//! exactly as its text form, within a `<pre>` element, but each styled component is enclosed in
+//! a `<span>` element whose class is that of its kind, see
+//! [`ReprComponentKind::html_class`](crate::repr::ReprComponentKind::html_class).
+//! Bytes included in an annotation of a dump also have one of the classes `wrapbin-annotation-0`
+//! to `wrapbin-annotation-5`, chosen in turn.
+//!
+//! ```html
+//! <pre class="wrapbin"><span class="wrapbin-prefix">0x</span><span class="wrapbin-delimiter">[</span>...
+//! ```
+//!
+//! The stylesheet [`STYLESHEET`], which follows the default color theme, may be included before
+//! the representation. Representations that are not styled, such as Base64, are escaped and
+//! enclosed in the `<pre>` element alone.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-html"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-html", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{array::ArrayFormatOptions, html::{html_representation, HtmlFormatOptions}}
+//! };
+//!
+//! let binary = Binary::from(b"<a>");
+//!
+//! assert_eq!(
+//!     html_representation(
+//!         &binary,
+//!         &HtmlFormatOptions::new(ArrayFormatOptions::default().with_lower_hex_bytes())),
+//!     concat!(
+//!         r#"<pre class="wrapbin"><span class="wrapbin-prefix">0x</span>"#,
+//!         r#"<span class="wrapbin-delimiter">[</span>"#,
+//!         r#"<span class="wrapbin-printable">3c</span><span class="wrapbin-separator">,</span> "#,
+//!         r#"<span class="wrapbin-printable">61</span><span class="wrapbin-separator">,</span> "#,
+//!         r#"<span class="wrapbin-printable">3e</span>"#,
+//!         r#"<span class="wrapbin-delimiter">]</span></pre>"#,
+//!     ),
+//! );
+//! ```
+//!
+
+use crate::{
+    repr::{format_with, write, BinaryFormatOptions, Markup},
+    Binary,
+};
+use alloc::string::String;
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    convert::Into,
+    fmt::{self, Debug, Write},
+    option::Option::Some,
+    result::Result::Ok,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Options for rendering a representation as HTML, see [`html_representation`].
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtmlFormatOptions {
+    representation: BinaryFormatOptions,
+    stylesheet: bool,
+}
+
+///
+/// Adapts a writer to escape the text written to it for HTML; the characters `&`, `<`, `>`, `"`
+/// and `'` are written as character references, and the escape character as its control
+/// picture.
+///
+pub(crate) struct HtmlEscaper<'a, W: Write + ?Sized>(pub(crate) &'a mut W);
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A stylesheet for the classes of the `<span>` elements in rendered representations, following
+/// the colors of the default theme.
+///
+pub const STYLESHEET: &str = r#"pre.wrapbin { font-family: ui-monospace, monospace; }
+.wrapbin-delimiter, .wrapbin-separator, .wrapbin-index { opacity: 0.6; }
+.wrapbin-difference { color: #ffffff; background-color: #cc0000; font-weight: bold; }
+.wrapbin-control { color: #ee3333; font-weight: bold; }
+.wrapbin-printable { color: #228822; font-weight: bold; }
+.wrapbin-printable-extended { color: #228822; }
+.wrapbin-undefined { color: #aa8800; }
+.wrapbin-annotation-0 { background-color: #cfe2ff; }
+.wrapbin-annotation-1 { background-color: #f5d0f5; }
+.wrapbin-annotation-2 { background-color: #cff4fc; }
+.wrapbin-annotation-3 { background-color: #fff3cd; }
+.wrapbin-annotation-4 { background-color: #f8d7da; }
+.wrapbin-annotation-5 { background-color: #d1e7dd; }
+"#;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn html_representation(value: &Binary<'_>, options: &HtmlFormatOptions) -> String {
+    format_with(value.len() * 32, |buffer| {
+        if options.stylesheet {
+            buffer.push_str("<style>\n");
+            buffer.push_str(STYLESHEET);
+            buffer.push_str("</style>\n");
+        }
+        buffer.push_str(r#"<pre class="wrapbin">"#);
+        if options.representation.is_styled() {
+            // Styled representations write the elements, and escape their text, themselves.
+            write(
+                buffer,
+                value,
+                options.representation.clone().with_markup(Markup::Html),
+            )?;
+        } else {
+            write(
+                &mut HtmlEscaper(&mut *buffer),
+                value,
+                options.representation.clone(),
+            )?;
+        }
+        buffer.push_str("</pre>");
+        Ok(())
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) const ANNOTATION_CLASSES: usize = 6;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<W: Write + ?Sized> Write for HtmlEscaper<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut rest = s;
+        while let Some(index) = rest.find(['&', '<', '>', '"', '\'', '\u{1b}']) {
+            self.0.write_str(&rest[..index])?;
+            self.0.write_str(match rest.as_bytes()[index] {
+                b'&' => "&amp;",
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'"' => "&quot;",
+                b'\'' => "&#39;",
+                _ => "\u{241b}",
+            })?;
+            rest = &rest[index + 1..];
+        }
+        self.0.write_str(rest)
+    }
+}
+
+impl HtmlFormatOptions {
+    /// Render the representation with the given options; any use of color is replaced by the
+    /// classes of the `<span>` elements.
+    pub fn new<O: Into<BinaryFormatOptions>>(representation: O) -> Self {
+        Self {
+            representation: representation.into(),
+            stylesheet: false,
+        }
+    }
+
+    /// Include the bundled [`STYLESHEET`], in a `<style>` element, before the representation.
+    pub fn with_stylesheet(mut self, stylesheet: bool) -> Self {
+        self.stylesheet = stylesheet;
+        self
+    }
+}
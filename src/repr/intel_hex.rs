@@ -0,0 +1,478 @@
+//!
+//! An [Intel HEX](https://en.wikipedia.org/wiki/Intel_HEX) representation of binary data, as
+//! used to load firmware images into microcontrollers and EEPROM programmers.
+//!
+//! ```ebnf
+//! IntelHexRepresentation ::= { Record '\n' } EndOfFileRecord '\n'
+//!
+//! Record ::= ':' ByteCount Address RecordType { Byte } Checksum
+//! ByteCount ::= Byte
+//! Address ::= Byte Byte
+//! RecordType ::= '00' | '01' | '02' | '03' | '04' | '05'
+//! Checksum ::= Byte
+//! EndOfFileRecord ::= ':00000001FF'
+//!
+//! Byte ::= Nybble Nybble
+//! Nybble ::= [0-9a-fA-F]
+//! ```
+//!
+//! Data records hold at most 16 or 32 bytes and never cross a 64K boundary; when the address of a
+//! record is past the first 64K an *extended linear address* (`04`) record, or an *extended
+//! segment address* (`02`) record, sets the upper part of the address for the records following
+//! it. An optional *start linear address* (`05`) or *start segment address* (`03`) record is
+//! written before the end of file record.
+//!
+//! Extended segment addresses reach only the first 1M, and linear addresses the first 4G; a value
+//! that extends past the addresses of its mode cannot be represented, see
+//! [`try_intel_hex_representation`].
+//!
+//! When parsing, each record's checksum is verified and errors report the, one-based, line number
+//! of the record. Data records need not be contiguous, the resulting value starts at the lowest
+//! address and any gaps between records are filled with `0xFF`, the value of erased flash memory;
+//! gaps that add up to more than 16M are an error rather than a value mostly made of fill.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-intel-hex"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-intel-hex", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, format, intel_hex::IntelHexFormatOptions}
+//! };
+//!
+//! let binary = Binary::from([
+//!     0x7b_u8,0xe6_u8,0xd4_u8,0xf2_u8,0x25_u8,0x5c_u8,0x62_u8,0xd3_u8,
+//!     0x21_u8,0x24_u8,0xab_u8,0x7e_u8,0x40_u8,0xf1_u8,0x7b_u8,0xce_u8,
+//!     0x17_u8,0x3c_u8,0x08_u8,0xd2_u8,
+//! ]);
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         IntelHexFormatOptions::default().with_base_address(0x0100)),
+//!     vec![
+//!         ":100100007BE6D4F2255C62D32124AB7E40F17BCE2A",
+//!         ":04011000173C08D2BE",
+//!         ":00000001FF",
+//!         "",
+//!     ].join("\n")
+//! );
+//! ```
+//!
+
+use crate::{
+    error::{offset_in, Error, ParseError},
+    repr::{assemble_records, format_with, BinaryFormatOptions},
+    Binary,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IntelHexFormatOptions {
+    record_length: IntelHexRecordLength,
+    address_mode: IntelHexAddressMode,
+    base_address: u32,
+    start_address: Option<IntelHexStartAddress>,
+}
+
+///
+/// The maximum number of data bytes in each data record.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(usize)]
+pub enum IntelHexRecordLength {
+    #[default]
+    Sixteen = 16,
+    ThirtyTwo = 32,
+}
+
+///
+/// The record type used to set the upper part of addresses past the first 64K.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntelHexAddressMode {
+    /// Extended linear address (`04`) records, addressing up to 4G.
+    #[default]
+    Linear,
+    /// Extended segment address (`02`) records, addressing up to 1M.
+    Segment,
+}
+
+///
+/// The execution start address held in a start address record.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntelHexStartAddress {
+    /// A start segment address (`03`) record, with the 80x86 `CS` and `IP` register values.
+    Segment {
+        code_segment: u16,
+        instruction_pointer: u16,
+    },
+    /// A start linear address (`05`) record, with the 80386 `EIP` register value.
+    Linear(u32),
+}
+
+///
+/// The result of parsing an Intel HEX representation, the data along with the address of its
+/// first byte and any start address.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntelHexImage {
+    base_address: u32,
+    start_address: Option<IntelHexStartAddress>,
+    binary: Binary<'static>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Format `value` as Intel HEX.
+///
+/// # Panics
+///
+/// If `value` extends past the highest address of the address mode, see
+/// [`try_intel_hex_representation`] to return an error instead.
+///
+pub fn intel_hex_representation(value: &Binary<'_>, options: &IntelHexFormatOptions) -> String {
+    format_with(
+        (value.len() / options.record_length.byte_count() + 2)
+            * (options.record_length.byte_count() * 2 + 12),
+        |buffer| write_intel_hex_representation(buffer, value, options),
+    )
+}
+
+///
+/// Write the representation of `value` to `w`, see [`intel_hex_representation`]. Nothing is
+/// written, and an error returned, if `value` extends past the highest address of the address
+/// mode.
+///
+pub fn write_intel_hex_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &IntelHexFormatOptions,
+) -> fmt::Result {
+    options.check_address_range(value).map_err(|_| fmt::Error)?;
+    let record_length = options.record_length.byte_count();
+    let mut address = options.base_address;
+    let mut page: u32 = 0;
+    let mut remaining: &[u8] = value.as_ref();
+    while !remaining.is_empty() {
+        if address >> 16 != page {
+            page = address >> 16;
+            match options.address_mode {
+                IntelHexAddressMode::Linear => {
+                    write_record(w, EXTENDED_LINEAR_ADDRESS, 0, &(page as u16).to_be_bytes())
+                }
+                IntelHexAddressMode::Segment => write_record(
+                    w,
+                    EXTENDED_SEGMENT_ADDRESS,
+                    0,
+                    &((page << 12) as u16).to_be_bytes(),
+                ),
+            }?;
+        }
+        let offset = address & 0xFFFF;
+        let length = record_length
+            .min(remaining.len())
+            .min((0x10000 - offset) as usize);
+        let (data, rest) = remaining.split_at(length);
+        write_record(w, DATA, offset as u16, data)?;
+        address = address.wrapping_add(length as u32);
+        remaining = rest;
+    }
+    match options.start_address {
+        Some(IntelHexStartAddress::Segment {
+            code_segment,
+            instruction_pointer,
+        }) => {
+            let mut data = [0; 4];
+            data[..2].copy_from_slice(&code_segment.to_be_bytes());
+            data[2..].copy_from_slice(&instruction_pointer.to_be_bytes());
+            write_record(w, START_SEGMENT_ADDRESS, 0, &data)?;
+        }
+        Some(IntelHexStartAddress::Linear(address)) => {
+            write_record(w, START_LINEAR_ADDRESS, 0, &address.to_be_bytes())?;
+        }
+        None => {}
+    }
+    write_record(w, END_OF_FILE, 0, &[])?;
+    Ok(())
+}
+
+///
+/// Format `value` as Intel HEX, returning an error if `value` extends past the highest address of
+/// the address mode: 1M for extended segment addresses, and 4G for linear ones.
+///
+pub fn try_intel_hex_representation(
+    value: &Binary<'_>,
+    options: &IntelHexFormatOptions,
+) -> Result<String, Error> {
+    options.check_address_range(value)?;
+    Ok(intel_hex_representation(value, options))
+}
+
+///
+/// Parse an Intel HEX representation, returning only the data; see [`parse_intel_hex_image`] to
+/// also retrieve the base and start addresses.
+///
+pub fn parse_intel_hex_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    parse_intel_hex_image(s).map(IntelHexImage::into_binary)
+}
+
+///
+/// Parse an Intel HEX representation, verifying the checksum of each record. Blank lines are
+/// ignored, an end of file record is required, and no records may follow it.
+///
+pub fn parse_intel_hex_image(s: &str) -> Result<IntelHexImage, ParseError> {
+    let mut upper_address: u32 = 0;
+    let mut start_address = None;
+    let mut records: Vec<(u32, Vec<u8>, &str)> = Vec::new();
+    let mut end_of_file = false;
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid =
+            |expected| Error::InvalidRecord.at(s, offset_in(s, line), line.len(), Some(expected));
+        if end_of_file {
+            return Err(invalid("no records after the end of file record"));
+        }
+        let (record_type, address, data) = parse_record(s, line)?;
+        match (record_type, data.len()) {
+            (DATA, _) => records.push((upper_address.wrapping_add(u32::from(address)), data, line)),
+            (END_OF_FILE, 0) => end_of_file = true,
+            (EXTENDED_SEGMENT_ADDRESS, 2) => {
+                upper_address = u32::from(u16::from_be_bytes([data[0], data[1]])) << 4
+            }
+            (START_SEGMENT_ADDRESS, 4) => {
+                start_address = Some(IntelHexStartAddress::Segment {
+                    code_segment: u16::from_be_bytes([data[0], data[1]]),
+                    instruction_pointer: u16::from_be_bytes([data[2], data[3]]),
+                })
+            }
+            (EXTENDED_LINEAR_ADDRESS, 2) => {
+                upper_address = u32::from(u16::from_be_bytes([data[0], data[1]])) << 16
+            }
+            (START_LINEAR_ADDRESS, 4) => {
+                start_address = Some(IntelHexStartAddress::Linear(u32::from_be_bytes([
+                    data[0], data[1], data[2], data[3],
+                ])))
+            }
+            _ => return Err(invalid("a record type with a matching data length")),
+        }
+    }
+    if !end_of_file {
+        return Err(Error::InvalidRecord.at(s, s.len(), 0, Some("an end of file record")));
+    }
+
+    let (base_address, values) = assemble_records(s, records)?;
+    Ok(IntelHexImage {
+        base_address,
+        start_address,
+        binary: Binary::from(values),
+    })
+}
+
+///
+/// Detect the options used to produce an Intel HEX representation, returns `None` unless every
+/// non-blank line starts with `:`.
+///
+pub(crate) fn detect_intel_hex_options(s: &str) -> Option<IntelHexFormatOptions> {
+    let mut lines = s
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .peekable();
+    lines.peek()?;
+    let mut options = IntelHexFormatOptions::default();
+    for line in lines {
+        let record = line.strip_prefix(':')?;
+        match (record.get(..2), record.get(6..8)) {
+            (Some("20"), Some("00")) => {
+                options = options.with_record_length(IntelHexRecordLength::ThirtyTwo)
+            }
+            (_, Some("02")) => options = options.with_address_mode(IntelHexAddressMode::Segment),
+            _ => {}
+        }
+    }
+    Some(options)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const DATA: u8 = 0x00;
+const END_OF_FILE: u8 = 0x01;
+const EXTENDED_SEGMENT_ADDRESS: u8 = 0x02;
+const START_SEGMENT_ADDRESS: u8 = 0x03;
+const EXTENDED_LINEAR_ADDRESS: u8 = 0x04;
+const START_LINEAR_ADDRESS: u8 = 0x05;
+
+fn write_record<W: Write + ?Sized>(
+    w: &mut W,
+    record_type: u8,
+    address: u16,
+    data: &[u8],
+) -> fmt::Result {
+    let [address_high, address_low] = address.to_be_bytes();
+    let header = [data.len() as u8, address_high, address_low, record_type];
+    let sum = header
+        .iter()
+        .chain(data.iter())
+        .fold(0_u8, |sum, byte| sum.wrapping_add(*byte));
+    w.write_char(':')?;
+    for byte in header.iter().chain(data.iter()) {
+        write!(w, "{byte:02X}")?;
+    }
+    writeln!(w, "{:02X}", sum.wrapping_neg())
+}
+
+///
+/// Parse a single record, `line`, which is a slice of `s`; any error is located in `s`.
+///
+fn parse_record(s: &str, line: &str) -> Result<(u8, u16, Vec<u8>), ParseError> {
+    let start = offset_in(s, line);
+    let invalid = |offset: usize, length: usize, expected| {
+        Error::InvalidRecord.at(s, start + offset, length, Some(expected))
+    };
+    let Some(record) = line.strip_prefix(':') else {
+        return Err(invalid(0, 1, "`:`"));
+    };
+    if !record.is_ascii() || !record.len().is_multiple_of(2) || record.len() < 10 {
+        return Err(invalid(
+            0,
+            line.len(),
+            "a record of at least 5 hexadecimal byte values",
+        ));
+    }
+    let bytes = (0..record.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&record[i..i + 2], 16)
+                .map_err(|_| invalid(i + 1, 2, "a hexadecimal byte value"))
+        })
+        .collect::<Result<Vec<u8>, ParseError>>()?;
+    if bytes[0] as usize != bytes.len() - 5 {
+        return Err(invalid(
+            1,
+            2,
+            "a byte count matching the length of the data",
+        ));
+    }
+    if bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte)) != 0 {
+        return Err(Error::RecordChecksumMismatch.at(
+            s,
+            start + line.len() - 2,
+            2,
+            Some("the checksum of the record"),
+        ));
+    }
+    Ok((
+        bytes[3],
+        u16::from_be_bytes([bytes[1], bytes[2]]),
+        bytes[4..bytes.len() - 1].to_vec(),
+    ))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<IntelHexFormatOptions> for BinaryFormatOptions {
+    fn from(value: IntelHexFormatOptions) -> Self {
+        Self::IntelHex(value)
+    }
+}
+
+impl IntelHexFormatOptions {
+    /// Sets the maximum number of data bytes per record to be one of the values of the enum
+    /// [`IntelHexRecordLength`].
+    pub fn with_record_length(mut self, record_length: IntelHexRecordLength) -> Self {
+        self.record_length = record_length;
+        self
+    }
+
+    /// Sets the record type used for addresses past the first 64K to be one of the values of the
+    /// enum [`IntelHexAddressMode`].
+    pub fn with_address_mode(mut self, address_mode: IntelHexAddressMode) -> Self {
+        self.address_mode = address_mode;
+        self
+    }
+
+    /// Sets the address of the first byte of the value.
+    pub fn with_base_address(mut self, base_address: u32) -> Self {
+        self.base_address = base_address;
+        self
+    }
+
+    /// Sets the execution start address, if any, written before the end of file record.
+    pub fn with_start_address(mut self, start_address: Option<IntelHexStartAddress>) -> Self {
+        self.start_address = start_address;
+        self
+    }
+
+    pub(crate) fn check_address_range(&self, value: &Binary<'_>) -> Result<(), Error> {
+        let limit = self.address_mode.highest_address();
+        match (u64::from(self.base_address) + value.len() as u64).checked_sub(1) {
+            Some(address) if address > limit => Err(Error::AddressOutOfRange { address, limit }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntelHexAddressMode {
+    /// Return the highest address that records in this mode can hold.
+    pub const fn highest_address(&self) -> u64 {
+        match self {
+            Self::Linear => 0xFFFF_FFFF,
+            Self::Segment => 0xF_FFFF,
+        }
+    }
+}
+
+impl IntelHexRecordLength {
+    #[inline(always)]
+    pub const fn byte_count(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl IntelHexImage {
+    /// Return the address of the first byte of the data.
+    pub const fn base_address(&self) -> u32 {
+        self.base_address
+    }
+
+    /// Return the execution start address, if the representation included one.
+    pub const fn start_address(&self) -> Option<IntelHexStartAddress> {
+        self.start_address
+    }
+
+    /// Return a reference to the data.
+    pub const fn binary(&self) -> &Binary<'static> {
+        &self.binary
+    }
+
+    /// Return the data, consuming the image.
+    pub fn into_binary(self) -> Binary<'static> {
+        self.binary
+    }
+}
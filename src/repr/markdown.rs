@@ -0,0 +1,120 @@
+//!
+//! Render a representation as Markdown, for inclusion in reports. Any representation may be
+//! rendered as a fenced code block, and a dump may also be rendered as a GitHub Flavored
+//! Markdown table, with a row for each line of the dump and a column for each of its cells.
+//!
+//! ```markdown
+//! | Offset | 00 | 01 | 02 | 03 | Text |
+//! | --- | --- | --- | --- | --- | --- |
+//! | 000000 | 48 | 69 | 21 | 0A | Hi\!. |
+//! ```
+//!
+//! Neither form is styled. Characters with a meaning in Markdown are escaped in the cells of a
+//! table, the fence of a code block is longer than any run of backticks in the representation.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-markdown"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-markdown", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{dump::DumpFormatOptions, markdown::{markdown_dump_table, markdown_representation}}
+//! };
+//!
+//! let binary = Binary::from(b"Hi!\n");
+//! let options = DumpFormatOptions::default()
+//!     .with_bytes_per_line(4)
+//!     .show_ascii_gutter(true);
+//!
+//! assert_eq!(
+//!     markdown_representation(&binary, options.clone()),
+//!     vec![
+//!         "```text",
+//!         "0X       00 01 02 03 ",
+//!         "         ────────────",
+//!         "000000:  48 69 21 0A  |Hi!.|",
+//!         "```",
+//!     ].join("\n")
+//! );
+//!
+//! assert_eq!(
+//!     markdown_dump_table(&binary, &options),
+//!     vec![
+//!         "| Offset | 00 | 01 | 02 | 03 | Text |",
+//!         "| --- | --- | --- | --- | --- | --- |",
+//!         "| 000000 | 48 | 69 | 21 | 0A | Hi\\!. |",
+//!     ].join("\n")
+//! );
+//! ```
+//!
+
+use crate::{
+    repr::{
+        self,
+        dump::{dump_table, DumpFormatOptions},
+        BinaryFormatOptions, Markup,
+    },
+    Binary,
+};
+use alloc::{format, string::String, vec::Vec};
+use core::{convert::Into, iter::Iterator};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Render any representation, without styling, as a fenced code block.
+///
+pub fn markdown_representation<O: Into<BinaryFormatOptions>>(
+    value: &Binary<'_>,
+    options: O,
+) -> String {
+    let text = repr::format(value, options.into().with_markup(Markup::None));
+    let text = text.trim_end_matches('\n');
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    format!("{fence}text\n{text}\n{fence}")
+}
+
+///
+/// Render a dump as a table, with a header row for the offset of each line, the offset of each
+/// cell within a line, and any ASCII gutter and annotation labels.
+///
+pub fn markdown_dump_table(value: &Binary<'_>, options: &DumpFormatOptions) -> String {
+    let (header, rows) = dump_table(value, options);
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+    lines.push(format_row(&header));
+    lines.push(format_row(
+        &header.iter().map(|_| "---".into()).collect::<Vec<_>>(),
+    ));
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    lines.join("\n")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn format_row(cells: &[String]) -> String {
+    let cells: Vec<String> = cells.iter().map(|cell| escape_cell(cell)).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+fn escape_cell(cell: &str) -> String {
+    let mut buffer = String::with_capacity(cell.len());
+    for c in cell.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '!' | '#'
+        ) {
+            buffer.push('\\');
+        }
+        buffer.push(c);
+    }
+    buffer
+}
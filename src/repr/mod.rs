@@ -154,23 +154,44 @@
 //! ```
 //!
 
-use crate::error::Error;
 #[cfg(any(
     feature = "repr-array",
-    feature = "repr-base64",
     feature = "repr-dump",
+    feature = "repr-intel-hex",
+    feature = "repr-srec",
     feature = "repr-string"
 ))]
-use crate::Binary; // only used in format() function.
-use alloc::{format, string::String};
+use crate::error::offset_in;
+#[cfg(any(feature = "repr-array", feature = "repr-dump", feature = "repr-string"))]
+use crate::error::parse_error;
+use crate::error::Error;
+#[cfg(feature = "repr-any")]
+use crate::error::ParseError;
+#[cfg(feature = "repr-color")]
+pub use crate::repr::color::{
+    ByteCategory, ByteStyling, ColorDepth, ColorPolicy, ColorStream, Theme,
+};
+#[cfg(feature = "repr-any")]
+use crate::Binary; // only used in format(), write() and parse() functions.
+use alloc::string::String;
+#[cfg(any(feature = "repr-intel-hex", feature = "repr-srec"))]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "repr-any")]
+use core::fmt::{Display, Formatter};
 use core::{
     clone::Clone,
+    cmp::{Eq, PartialEq},
     default::Default,
-    fmt::Debug,
+    fmt::{self, Debug, Write},
     marker::Copy,
-    option::Option::{self, Some},
+    option::Option::{self, None, Some},
     result::Result::{self, Err, Ok},
+    str,
 };
+#[cfg(all(feature = "std", feature = "repr-any"))]
+use std::io;
+#[cfg(all(feature = "std", feature = "repr-any", feature = "repr-color"))]
+use std::io::IsTerminal;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -198,10 +219,24 @@ pub enum RadixFormat {
 pub enum BinaryFormatOptions {
     #[cfg(feature = "repr-array")]
     Array(ArrayFormatOptions),
+    #[cfg(feature = "repr-ascii85")]
+    Ascii85(Ascii85FormatOptions),
+    #[cfg(feature = "repr-base32")]
+    Base32(Base32FormatOptions),
+    #[cfg(feature = "repr-base58")]
+    Base58(Base58FormatOptions),
     #[cfg(feature = "repr-base64")]
     Base64(Base64FormatOptions),
     #[cfg(feature = "repr-dump")]
     Dump(DumpFormatOptions),
+    #[cfg(feature = "repr-escaped")]
+    Escaped(EscapedFormatOptions),
+    #[cfg(feature = "repr-intel-hex")]
+    IntelHex(IntelHexFormatOptions),
+    #[cfg(feature = "repr-source")]
+    Source(SourceFormatOptions),
+    #[cfg(feature = "repr-srec")]
+    SRecord(SRecordFormatOptions),
     #[cfg(feature = "repr-string")]
     String(StringFormatOptions),
 }
@@ -234,33 +269,875 @@ pub enum ReprComponentKind {
     Separator,
     /// Index values in the dump format that provide line and column numbers.
     Index,
+    /// Bytes that differ between two values being compared.
+    Difference,
     /// Actual data bytes.
     Value(ByteKind),
 }
 
 // ------------------------------------------------------------------------------------------------
-// Public Functions
+// Private Types
 // ------------------------------------------------------------------------------------------------
 
 ///
-/// This function ...
+/// How the style of each component is marked up in a representation.
+///
+#[cfg(any(
+    feature = "repr-array",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Markup {
+    /// No styling at all.
+    #[default]
+    None,
+    /// ANSI escape sequences, using the styles of a theme.
+    #[cfg(feature = "repr-color")]
+    Ansi,
+    /// ANSI escape sequences if [`ColorPolicy::Auto`] allows color for the stream, resolved to
+    /// [`Markup::Ansi`] or [`Markup::None`] each time the representation is written.
+    #[cfg(all(
+        feature = "repr-color",
+        any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        )
+    ))]
+    Auto(ColorStream),
+    /// HTML `<span>` elements, with the text between them escaped, see [`html`].
+    #[cfg(feature = "repr-html")]
+    Html,
+}
+
+///
+/// The markup surrounding a single styled component, displayed in the form
+/// `{marker}component{marker:#}`, in the same way as an `anstyle::Style`.
 ///
 #[cfg(any(
     feature = "repr-array",
-    feature = "repr-base64",
     feature = "repr-dump",
+    feature = "repr-escaped",
     feature = "repr-string"
 ))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Marker {
+    None,
+    #[cfg(feature = "repr-color")]
+    Ansi(color::Style),
+    /// The kind of component, and the index of any annotation that includes it.
+    #[cfg(feature = "repr-html")]
+    Html(ReprComponentKind, Option<usize>),
+}
+
+///
+/// Text of a representation, displayed escaped as its markup requires, see [`Markup::text`].
+///
+#[cfg(any(
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+pub(crate) struct MarkupText<T>(T, Markup);
+
+///
+/// Adapts an I/O stream to [`Write`], keeping the I/O error that a formatter error stands for.
+///
+#[cfg(all(feature = "std", feature = "repr-any"))]
+struct IoWriter<'a, W: io::Write + ?Sized> {
+    inner: &'a mut W,
+    error: io::Result<()>,
+}
+
+///
+/// Displays a value in the representation selected by its options, see [`Binary::display`].
+///
+#[cfg(feature = "repr-any")]
+pub(crate) struct DisplayRepresentation<'a> {
+    pub(crate) value: Binary<'a>,
+    pub(crate) options: BinaryFormatOptions,
+}
+
+///
+/// Measures the width of a representation, in characters less those of any ANSI escape
+/// sequences; writing fails once the width reaches `limit`, or at the end of the first line,
+/// as there is then no padding to add.
+///
+#[cfg(feature = "repr-any")]
+struct DisplayWidth {
+    width: usize,
+    limit: usize,
+    escape: EscapeState,
+}
+
+#[cfg(feature = "repr-any")]
+#[derive(Clone, Copy)]
+enum EscapeState {
+    None,
+    Escape,
+    ControlSequence,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Format `value` in the representation selected by `options`, see [`write()`].
+///
+/// # Panics
+///
+/// If `value` cannot be written in the representation with `options`, such as a Z85 value whose
+/// length is not a multiple of 4 or a record-based value that extends past the end of the address
+/// space, see [`try_format`] to return an error instead.
+///
+#[cfg(feature = "repr-any")]
 pub fn format<O: Into<BinaryFormatOptions>>(value: &Binary<'_>, options: O) -> String {
-    match options.into() {
+    format_with(value.len() * 2, |buffer| write(buffer, value, options))
+}
+
+///
+/// Format `value` in the representation selected by `options`, returning an error if `value`
+/// cannot be written in that representation with `options`.
+///
+#[cfg(feature = "repr-any")]
+pub fn try_format<O: Into<BinaryFormatOptions>>(
+    value: &Binary<'_>,
+    options: O,
+) -> Result<String, Error> {
+    let options = options.into();
+    match &options {
         #[cfg(feature = "repr-array")]
-        BinaryFormatOptions::Array(options) => array_representation(value, &options),
+        BinaryFormatOptions::Array(_) => {}
+        #[cfg(feature = "repr-ascii85")]
+        BinaryFormatOptions::Ascii85(options) => options.check_length(value)?,
+        #[cfg(feature = "repr-base32")]
+        BinaryFormatOptions::Base32(_) => {}
+        #[cfg(feature = "repr-base58")]
+        BinaryFormatOptions::Base58(_) => {}
         #[cfg(feature = "repr-base64")]
-        BinaryFormatOptions::Base64(options) => base64_representation(value, &options),
+        BinaryFormatOptions::Base64(_) => {}
         #[cfg(feature = "repr-dump")]
-        BinaryFormatOptions::Dump(options) => dump_representation(value, &options),
+        BinaryFormatOptions::Dump(_) => {}
+        #[cfg(feature = "repr-escaped")]
+        BinaryFormatOptions::Escaped(_) => {}
+        #[cfg(feature = "repr-intel-hex")]
+        BinaryFormatOptions::IntelHex(options) => options.check_address_range(value)?,
+        #[cfg(feature = "repr-source")]
+        BinaryFormatOptions::Source(_) => {}
+        #[cfg(feature = "repr-srec")]
+        BinaryFormatOptions::SRecord(options) => {
+            options.check_header_length()?;
+            options.check_address_range(value)?;
+        }
+        #[cfg(feature = "repr-string")]
+        BinaryFormatOptions::String(_) => {}
+    }
+    Ok(format(value, options))
+}
+
+///
+/// Write `value`, in the representation selected by `options`, to `w`. The representation is
+/// streamed to the writer as it is formatted, rather than being built as a string first.
+///
+#[cfg(feature = "repr-any")]
+pub fn write<W, O>(w: &mut W, value: &Binary<'_>, options: O) -> fmt::Result
+where
+    W: Write + ?Sized,
+    O: Into<BinaryFormatOptions>,
+{
+    write_options(w, value, &options.into())
+}
+
+///
+/// Write `value`, in the representation selected by `options`, to the I/O stream `w`; see
+/// [`write()`].
+///
+#[cfg(all(feature = "std", feature = "repr-any"))]
+pub fn write_io<W, O>(w: &mut W, value: &Binary<'_>, options: O) -> io::Result<()>
+where
+    W: io::Write + ?Sized,
+    O: Into<BinaryFormatOptions>,
+{
+    let mut adapter = IoWriter {
+        inner: w,
+        error: Ok(()),
+    };
+    match write(&mut adapter, value, options) {
+        Ok(()) => Ok(()),
+        Err(_) => match adapter.error {
+            Err(error) => Err(error),
+            Ok(()) => Err(io::Error::other("formatter error")),
+        },
+    }
+}
+
+///
+/// Write `value`, in the representation selected by `options`, to the I/O stream `w`, see
+/// [`write_io()`]; a [`ColorPolicy::Auto`] policy is resolved by checking whether `w` itself is a
+/// terminal, rather than the stream named by the policy.
+///
+#[cfg(all(feature = "std", feature = "repr-any", feature = "repr-color"))]
+pub fn write_terminal<W, O>(w: &mut W, value: &Binary<'_>, options: O) -> io::Result<()>
+where
+    W: io::Write + IsTerminal + ?Sized,
+    O: Into<BinaryFormatOptions>,
+{
+    let is_terminal = w.is_terminal();
+    write_io(w, value, options.into().for_terminal(is_terminal))
+}
+
+///
+/// Parse a string in any of the enabled representations, the representation is determined by
+/// [`detect_representation`] and the string passed to the corresponding parse function.
+///
+#[cfg(feature = "repr-any")]
+pub fn parse(s: &str) -> Result<Binary<'_>, ParseError> {
+    let trimmed = s.trim();
+    let leading = s.len() - s.trim_start().len();
+    let unrecognized = |expected| {
+        Error::InvalidRepresentation.at(
+            s,
+            leading,
+            trimmed.lines().next().map(str::len).unwrap_or_default(),
+            Some(expected),
+        )
+    };
+    // Errors are located in the trimmed string, except for dumps, and are moved to `s`.
+    let (result, offset) = match detect_representation(s) {
+        #[cfg(feature = "repr-array")]
+        Some(BinaryFormatOptions::Array(_)) => (parse_array_representation(trimmed), leading),
+        #[cfg(feature = "repr-ascii85")]
+        Some(BinaryFormatOptions::Ascii85(options)) => (
+            parse_ascii85_representation_with(trimmed, &options),
+            leading,
+        ),
+        #[cfg(feature = "repr-base32")]
+        Some(BinaryFormatOptions::Base32(options)) => {
+            (parse_base32_representation_with(trimmed, &options), leading)
+        }
+        #[cfg(feature = "repr-base58")]
+        Some(BinaryFormatOptions::Base58(options)) => {
+            (parse_base58_representation_with(trimmed, &options), leading)
+        }
+        #[cfg(feature = "repr-base64")]
+        Some(BinaryFormatOptions::Base64(options)) => {
+            (parse_base64_representation_with(trimmed, &options), leading)
+        }
+        #[cfg(feature = "repr-dump")]
+        Some(BinaryFormatOptions::Dump(_)) => (parse_dump_representation(s), 0),
+        #[cfg(feature = "repr-escaped")]
+        Some(BinaryFormatOptions::Escaped(_)) => (parse_escaped_representation(trimmed), leading),
+        #[cfg(feature = "repr-intel-hex")]
+        Some(BinaryFormatOptions::IntelHex(_)) => {
+            (parse_intel_hex_representation(trimmed), leading)
+        }
+        #[cfg(feature = "repr-source")]
+        Some(BinaryFormatOptions::Source(_)) => {
+            unreachable!("the source representation is never detected")
+        }
+        #[cfg(feature = "repr-srec")]
+        Some(BinaryFormatOptions::SRecord(_)) => (parse_srec_representation(trimmed), leading),
         #[cfg(feature = "repr-string")]
-        BinaryFormatOptions::String(options) => string_representation(value, &options),
+        Some(BinaryFormatOptions::String(_)) => (parse_string_representation(trimmed), leading),
+        None => (Err(unrecognized("one of the enabled representations")), 0),
+    };
+    result.map_err(|error| error.offset_by(s, offset))
+}
+
+///
+/// Detect which of the enabled representations the string `s` is in, returning the options
+/// that will format a value back into the same representation. The following rules are
+/// applied in order:
+///
+/// 1. a radix prefix followed by `[` is an array representation,
+/// 2. a radix prefix followed by `"` is a string representation,
+/// 3. a string enclosed in `"`, with an optional `b` prefix, is an escaped representation,
+/// 4. a string enclosed in `<~` and `~>` is an Adobe Ascii85 representation,
+/// 5. lines that all start with `:` are an Intel HEX representation,
+/// 6. lines that all start with `S`, a record type digit, and hex digits are an S-record
+///    representation,
+/// 7. a header line and/or lines starting with an index and `:` is a dump representation,
+/// 8. a string containing only characters from the base64 alphabet is a base64 representation,
+///    unless it is only letters and digits that are either all hex digits or not a whole number
+///    of four character groups, as such text is as likely to be something else.
+///
+/// Note that base32, base58, and undelimited Ascii85 representations are never detected, as their
+/// alphabets overlap with the base64 alphabet; use the parse functions in the corresponding
+/// modules directly. The source representation is format-only and is never detected.
+///
+#[cfg(feature = "repr-any")]
+pub fn detect_representation(s: &str) -> Option<BinaryFormatOptions> {
+    let trimmed = s.trim();
+    let mut chars = trimmed.chars();
+    if let (Some('0'), Some(radix_char), Some(delimiter)) =
+        (chars.next(), chars.next(), chars.next())
+    {
+        #[cfg_attr(
+            not(any(feature = "repr-array", feature = "repr-string")),
+            allow(unused_variables)
+        )]
+        if let Ok(radix_format) = RadixFormat::from(Some(radix_char)) {
+            match delimiter {
+                #[cfg(feature = "repr-array")]
+                '[' => {
+                    return Some(
+                        ArrayFormatOptions::default()
+                            .with_byte_radix_format(radix_format)
+                            .compact(!trimmed.contains(' '))
+                            .into(),
+                    )
+                }
+                #[cfg(feature = "repr-string")]
+                '"' => {
+                    return Some(
+                        StringFormatOptions::default()
+                            .with_byte_radix_format(radix_format)
+                            .compact(!trimmed.contains('_'))
+                            .into(),
+                    )
+                }
+                _ => {}
+            }
+        }
+    }
+    #[cfg(feature = "repr-escaped")]
+    if let Some(options) = detect_escaped_options(trimmed) {
+        return Some(options.into());
+    }
+    #[cfg(feature = "repr-ascii85")]
+    if trimmed.starts_with("<~") && trimmed.ends_with("~>") {
+        return Some(Ascii85FormatOptions::adobe().into());
+    }
+    #[cfg(feature = "repr-intel-hex")]
+    if let Some(options) = detect_intel_hex_options(trimmed) {
+        return Some(options.into());
+    }
+    #[cfg(feature = "repr-srec")]
+    if let Some(options) = detect_srec_options(trimmed) {
+        return Some(options.into());
+    }
+    #[cfg(feature = "repr-dump")]
+    if let Some(options) = detect_dump_options(s) {
+        return Some(options.into());
+    }
+    #[cfg(feature = "repr-base64")]
+    if let Some(options) = detect_base64_options(trimmed) {
+        return Some(options.into());
+    }
+    None
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ReprComponentKind
+// ------------------------------------------------------------------------------------------------
+
+impl ReprComponentKind {
+    ///
+    /// The class of the HTML `<span>` element enclosing components of this kind, for a
+    /// [`ReprComponentKind::Value`] this is the class of its byte kind.
+    ///
+    pub const fn html_class(&self) -> &'static str {
+        match self {
+            Self::Prefix => "wrapbin-prefix",
+            Self::Delimiter => "wrapbin-delimiter",
+            Self::Separator => "wrapbin-separator",
+            Self::Index => "wrapbin-index",
+            Self::Difference => "wrapbin-difference",
+            Self::Value(ByteKind::Control) => "wrapbin-control",
+            Self::Value(ByteKind::Printable) => "wrapbin-printable",
+            Self::Value(ByteKind::PrintableExtended) => "wrapbin-printable-extended",
+            Self::Value(ByteKind::Undefined) => "wrapbin-undefined",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ ByteKind
+// ------------------------------------------------------------------------------------------------
+
+impl ByteKind {
+    ///
+    /// Classify a single byte, treating bytes above `0x7F` as ISO 8859-1 (Latin-1) characters.
+    ///
+    pub const fn from_byte(byte: u8) -> Self {
+        match byte {
+            0x00..=0x20 => Self::Control,
+            0x21..=0x7E => Self::Printable,
+            0x7F => Self::Control,
+            0x80..=0x9F => Self::Undefined,
+            0xA0 => Self::Control,
+            0xA1..=0xAC => Self::PrintableExtended,
+            0xAD => Self::Control,
+            0xAE..=0xFF => Self::PrintableExtended,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Format Options ❱ BinaryFormatOptions
+// ------------------------------------------------------------------------------------------------
+
+impl BinaryFormatOptions {
+    ///
+    /// Sets the markup of the representations that support styling, all others are unchanged.
+    ///
+    #[cfg(any(feature = "repr-html", feature = "repr-markdown"))]
+    pub(crate) fn with_markup(self, markup: Markup) -> Self {
+        match self {
+            #[cfg(feature = "repr-array")]
+            Self::Array(options) => Self::Array(options.with_markup(markup)),
+            #[cfg(feature = "repr-dump")]
+            Self::Dump(options) => Self::Dump(options.with_markup(markup)),
+            #[cfg(feature = "repr-escaped")]
+            Self::Escaped(options) => Self::Escaped(options.with_markup(markup)),
+            #[cfg(feature = "repr-string")]
+            Self::String(options) => Self::String(options.with_markup(markup)),
+            #[allow(unreachable_patterns)]
+            other => other,
+        }
+    }
+
+    ///
+    /// Resolves the [`ColorPolicy::Auto`] policy of the representations that support styling for
+    /// a stream that is, or is not, a terminal; all others are unchanged.
+    ///
+    #[cfg(all(feature = "std", feature = "repr-any", feature = "repr-color"))]
+    #[cfg_attr(
+        not(any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        )),
+        allow(unused_variables)
+    )]
+    pub(crate) fn for_terminal(self, is_terminal: bool) -> Self {
+        match self {
+            #[cfg(feature = "repr-array")]
+            Self::Array(options) => Self::Array(options.for_terminal(is_terminal)),
+            #[cfg(feature = "repr-dump")]
+            Self::Dump(options) => Self::Dump(options.for_terminal(is_terminal)),
+            #[cfg(feature = "repr-escaped")]
+            Self::Escaped(options) => Self::Escaped(options.for_terminal(is_terminal)),
+            #[cfg(feature = "repr-string")]
+            Self::String(options) => Self::String(options.for_terminal(is_terminal)),
+            #[allow(unreachable_patterns)]
+            other => other,
+        }
+    }
+
+    ///
+    /// Returns `true` if this representation supports styling, and so writes its own markup.
+    ///
+    #[cfg(feature = "repr-html")]
+    pub(crate) fn is_styled(&self) -> bool {
+        match self {
+            #[cfg(feature = "repr-array")]
+            Self::Array(_) => true,
+            #[cfg(feature = "repr-dump")]
+            Self::Dump(_) => true,
+            #[cfg(feature = "repr-escaped")]
+            Self::Escaped(_) => true,
+            #[cfg(feature = "repr-string")]
+            Self::String(_) => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(any(feature = "repr-array", feature = "repr-dump", feature = "repr-string"))]
+pub(crate) const EXPECTED_RADIX_PREFIX: &str = "a radix prefix: `0b`, `0d`, `0o`, `0x` or `0X`";
+
+///
+/// Split the radix prefix, such as `0x`, from the start of `input`, returning the radix format
+/// and the remainder of `input`.
+///
+#[cfg(any(feature = "repr-array", feature = "repr-string"))]
+pub(crate) fn split_radix_prefix(input: &str) -> Result<(RadixFormat, &str), ParseError> {
+    let Some(rest) = input.strip_prefix('0') else {
+        return Err(Error::MissingRadixPrefix.at(input, 0, 1, Some(EXPECTED_RADIX_PREFIX)));
+    };
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(c @ ('b' | 'd' | 'o' | 'x' | 'X')) => {
+            let radix = RadixFormat::from(Some(c))
+                .map_err(|error| error.at(input, 0, 2, Some(EXPECTED_RADIX_PREFIX)))?;
+            Ok((radix, chars.as_str()))
+        }
+        _ => Err(Error::InvalidRadixPrefix.at(input, 0, 2, Some(EXPECTED_RADIX_PREFIX))),
+    }
+}
+
+///
+/// The most bytes of `0xFF` that parsing a record-based representation will fill the gaps between
+/// records with, the size of a 24-bit address space.
+///
+#[cfg(any(feature = "repr-intel-hex", feature = "repr-srec"))]
+pub(crate) const MAX_GAP_FILL: usize = 0x100_0000;
+
+///
+/// Assemble the data records of a record-based representation into a single value, returning the
+/// address of its first byte and the value. Each record is an address, its data, and the line of
+/// `s` it was parsed from. Gaps between records are filled with `0xFF`, the value of erased flash
+/// memory, but an error is returned if the gaps add up to more than [`MAX_GAP_FILL`] bytes.
+///
+#[cfg(any(feature = "repr-intel-hex", feature = "repr-srec"))]
+pub(crate) fn assemble_records(
+    s: &str,
+    records: Vec<(u32, Vec<u8>, &str)>,
+) -> Result<(u32, Vec<u8>), ParseError> {
+    let mut spans = records
+        .iter()
+        .map(|(address, data, line)| (u64::from(*address), data.len() as u64, *line))
+        .collect::<Vec<_>>();
+    spans.sort_by_key(|(address, _, _)| *address);
+    let base_address = spans
+        .first()
+        .map(|(address, _, _)| *address)
+        .unwrap_or_default();
+    let mut end_address = base_address;
+    let mut fill = 0;
+    for (address, length, line) in spans {
+        fill += address.saturating_sub(end_address);
+        if fill > MAX_GAP_FILL as u64 {
+            return Err(Error::AddressGapTooLarge {
+                limit: MAX_GAP_FILL,
+            }
+            .at(
+                s,
+                offset_in(s, line),
+                line.len(),
+                Some("a record closer to the records before it"),
+            ));
+        }
+        end_address = end_address.max(address + length);
+    }
+
+    let mut values = vec![0xFF; (end_address - base_address) as usize];
+    for (address, data, _) in records {
+        let offset = (u64::from(address) - base_address) as usize;
+        values[offset..offset + data.len()].copy_from_slice(&data);
+    }
+    Ok((base_address as u32, values))
+}
+
+///
+/// Collect the output of a `write_*` function into a string, for the functions that return one;
+/// `capacity` is an estimate of the length of the output.
+///
+pub(crate) fn format_with<F>(capacity: usize, write: F) -> String
+where
+    F: FnOnce(&mut String) -> fmt::Result,
+{
+    let mut buffer = String::with_capacity(capacity);
+    // Writing to a string only fails if the value cannot be written with the options given, which
+    // the callers document as a panic and check in their `try_` counterparts.
+    write(&mut buffer)
+        .expect("the value cannot be written in the representation with these options");
+    buffer
+}
+
+///
+/// Write `value` to `w` in the representation selected by `options`, without taking ownership of
+/// them, see [`write()`].
+///
+#[cfg(feature = "repr-any")]
+fn write_options<W>(w: &mut W, value: &Binary<'_>, options: &BinaryFormatOptions) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    match options {
+        #[cfg(feature = "repr-array")]
+        BinaryFormatOptions::Array(options) => write_array_representation(w, value, options),
+        #[cfg(feature = "repr-ascii85")]
+        BinaryFormatOptions::Ascii85(options) => write_ascii85_representation(w, value, options),
+        #[cfg(feature = "repr-base32")]
+        BinaryFormatOptions::Base32(options) => write_base32_representation(w, value, options),
+        #[cfg(feature = "repr-base58")]
+        BinaryFormatOptions::Base58(options) => write_base58_representation(w, value, options),
+        #[cfg(feature = "repr-base64")]
+        BinaryFormatOptions::Base64(options) => write_base64_representation(w, value, options),
+        #[cfg(feature = "repr-dump")]
+        BinaryFormatOptions::Dump(options) => write_dump_representation(w, value, options),
+        #[cfg(feature = "repr-escaped")]
+        BinaryFormatOptions::Escaped(options) => write_escaped_representation(w, value, options),
+        #[cfg(feature = "repr-intel-hex")]
+        BinaryFormatOptions::IntelHex(options) => write_intel_hex_representation(w, value, options),
+        #[cfg(feature = "repr-source")]
+        BinaryFormatOptions::Source(options) => write_source_representation(w, value, options),
+        #[cfg(feature = "repr-srec")]
+        BinaryFormatOptions::SRecord(options) => write_srec_representation(w, value, options),
+        #[cfg(feature = "repr-string")]
+        BinaryFormatOptions::String(options) => write_string_representation(w, value, options),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ DisplayRepresentation
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "repr-any")]
+impl Display for DisplayRepresentation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some(width) = f.width() else {
+            return write_options(f, &self.value, &self.options);
+        };
+        let mut measure = DisplayWidth {
+            width: 0,
+            limit: width,
+            escape: EscapeState::None,
+        };
+        if write_options(&mut measure, &self.value, &self.options).is_err() {
+            return write_options(f, &self.value, &self.options);
+        }
+        let padding = width - measure.width;
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        write_options(f, &self.value, &self.options)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "repr-any")]
+impl Write for DisplayWidth {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.escape = match (self.escape, c) {
+                (EscapeState::None, '\u{1b}') => EscapeState::Escape,
+                (EscapeState::None, '\n') => return Err(fmt::Error),
+                (EscapeState::None, _) => {
+                    self.width += 1;
+                    if self.width >= self.limit {
+                        return Err(fmt::Error);
+                    }
+                    EscapeState::None
+                }
+                (EscapeState::Escape, '[') => EscapeState::ControlSequence,
+                (EscapeState::ControlSequence, '\u{20}'..='\u{3f}') => EscapeState::ControlSequence,
+                (EscapeState::Escape | EscapeState::ControlSequence, _) => EscapeState::None,
+            };
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ IoWriter
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(all(feature = "std", feature = "repr-any"))]
+impl<W: io::Write + ?Sized> Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Err(error);
+            fmt::Error
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations ❱ Markup
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(any(
+    feature = "repr-array",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+impl Markup {
+    /// The markup of a representation that does, or does not, use color.
+    #[cfg(all(
+        feature = "repr-color",
+        any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        )
+    ))]
+    pub(crate) const fn from_color(colored: bool) -> Self {
+        if colored {
+            Self::Ansi
+        } else {
+            Self::None
+        }
+    }
+
+    /// Text, written as part of a representation, that is escaped as this markup requires; in
+    /// HTML it is escaped as by [`html::HtmlEscaper`].
+    #[cfg(any(
+        feature = "repr-dump",
+        feature = "repr-escaped",
+        feature = "repr-string"
+    ))]
+    pub(crate) const fn text<T: Display>(self, text: T) -> MarkupText<T> {
+        MarkupText(text, self)
+    }
+
+    /// The markup of a representation that uses color according to `policy`.
+    #[cfg(all(
+        feature = "repr-color",
+        any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        )
+    ))]
+    pub(crate) const fn from_policy(policy: ColorPolicy) -> Self {
+        match policy {
+            ColorPolicy::Always => Self::Ansi,
+            ColorPolicy::Never => Self::None,
+            ColorPolicy::Auto(stream) => Self::Auto(stream),
+        }
+    }
+
+    /// Resolves [`Markup::Auto`] by checking the environment and stream, see
+    /// [`ColorPolicy::use_color`].
+    #[cfg(any(
+        feature = "repr-array",
+        feature = "repr-dump",
+        feature = "repr-escaped",
+        feature = "repr-string"
+    ))]
+    pub(crate) fn resolve(self) -> Self {
+        #[cfg(feature = "repr-color")]
+        if let Self::Auto(stream) = self {
+            return Self::from_color(ColorPolicy::Auto(stream).use_color());
+        }
+        self
+    }
+
+    /// Resolves [`Markup::Auto`] for a stream that is, or is not, a terminal, see
+    /// [`ColorPolicy::use_color_for`].
+    #[cfg(all(
+        feature = "std",
+        feature = "repr-color",
+        any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        )
+    ))]
+    pub(crate) fn resolve_for(self, is_terminal: bool) -> Self {
+        match self {
+            Self::Auto(stream) => {
+                Self::from_color(ColorPolicy::Auto(stream).use_color_for(is_terminal))
+            }
+            markup => markup,
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "repr-array",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+impl Marker {
+    /// Write the start of the marker, which is nothing for [`Marker::None`].
+    #[cfg(any(
+        feature = "repr-array",
+        feature = "repr-dump",
+        feature = "repr-escaped",
+        feature = "repr-string"
+    ))]
+    #[inline]
+    pub(crate) fn write_start<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::None => Ok(()),
+            #[allow(unreachable_patterns)]
+            _ => write!(w, "{self}"),
+        }
+    }
+
+    /// Write the end of the marker, which is nothing for [`Marker::None`].
+    #[cfg(any(
+        feature = "repr-array",
+        feature = "repr-dump",
+        feature = "repr-escaped",
+        feature = "repr-string"
+    ))]
+    #[inline]
+    pub(crate) fn write_end<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Self::None => Ok(()),
+            #[allow(unreachable_patterns)]
+            _ => write!(w, "{self:#}"),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "repr-array",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+impl Display for Marker {
+    #[cfg_attr(
+        not(any(feature = "repr-color", feature = "repr-html")),
+        allow(unused_variables)
+    )]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => Ok(()),
+            #[cfg(feature = "repr-color")]
+            Self::Ansi(style) if f.alternate() => write!(f, "{style:#}"),
+            #[cfg(feature = "repr-color")]
+            Self::Ansi(style) => write!(f, "{style}"),
+            #[cfg(feature = "repr-html")]
+            Self::Html(_, _) if f.alternate() => f.write_str("</span>"),
+            #[cfg(feature = "repr-html")]
+            Self::Html(kind, annotation) => {
+                write!(f, r#"<span class="{}"#, kind.html_class())?;
+                if let Some(annotation) = annotation {
+                    write!(
+                        f,
+                        " wrapbin-annotation-{}",
+                        annotation % html::ANNOTATION_CLASSES
+                    )?;
+                }
+                f.write_str(r#"">"#)
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+impl<T: Display> Display for MarkupText<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.1 {
+            #[cfg(feature = "repr-html")]
+            Markup::Html => write!(html::HtmlEscaper(f), "{}", self.0),
+            _ => self.0.fmt(f),
+        }
     }
 }
 
@@ -301,18 +1178,34 @@ impl RadixFormat {
     /// fixed width or use a *compact* form.
     ///
     pub fn format(&self, byte: &u8, compact: bool) -> String {
-        match (self, compact) {
-            (RadixFormat::Binary, true) => format!("{byte:b}"),
-            (RadixFormat::Binary, false) => format!("{byte:08b}"),
-            (RadixFormat::Decimal, true) => format!("{byte}"),
-            (RadixFormat::Decimal, false) => format!("{byte:03}"),
-            (RadixFormat::LowerHex, true) => format!("{byte:x}"),
-            (RadixFormat::LowerHex, false) => format!("{byte:02x}"),
-            (RadixFormat::Octal, true) => format!("{byte:o}"),
-            (RadixFormat::Octal, false) => format!("{byte:03o}"),
-            (RadixFormat::UpperHex, true) => format!("{byte:X}"),
-            (RadixFormat::UpperHex, false) => format!("{byte:02X}"),
+        format_with(self.max_width(), |buffer| self.write(buffer, byte, compact))
+    }
+    ///
+    /// Write a single byte, formatted as by [`Self::format`], to `w`.
+    ///
+    pub fn write<W: Write + ?Sized>(&self, w: &mut W, byte: &u8, compact: bool) -> fmt::Result {
+        // Digits are written right to left into a buffer, which is already zero-padded, and the
+        // buffer written in one go; this avoids the cost of the formatting machinery per byte.
+        let digits: &[u8; 16] = match self {
+            RadixFormat::UpperHex => b"0123456789ABCDEF",
+            _ => b"0123456789abcdef",
+        };
+        let radix = self.radix();
+        let mut buffer = [b'0'; 8];
+        let mut start = buffer.len();
+        let mut value = u32::from(*byte);
+        loop {
+            start -= 1;
+            buffer[start] = digits[(value % radix) as usize];
+            value /= radix;
+            if value == 0 {
+                break;
+            }
         }
+        if !compact {
+            start = start.min(buffer.len() - self.max_width());
+        }
+        w.write_str(str::from_utf8(&buffer[start..]).map_err(|_| fmt::Error)?)
     }
     ///
     /// Attempt to parse a simgle character as a radix specifier.
@@ -340,6 +1233,32 @@ impl RadixFormat {
         }
     }
     ///
+    /// A description of a byte value in this radix, as expected when parsing.
+    ///
+    #[cfg(any(feature = "repr-array", feature = "repr-dump", feature = "repr-string"))]
+    pub(crate) const fn expected_byte(&self) -> &'static str {
+        match self {
+            RadixFormat::Binary => "a binary byte value",
+            RadixFormat::Octal => "an octal byte value",
+            RadixFormat::Decimal => "a decimal byte value",
+            RadixFormat::LowerHex | RadixFormat::UpperHex => "a hexadecimal byte value",
+        }
+    }
+    ///
+    /// Parse a single byte, `value`, which is a slice of `input`; any error is located in `input`.
+    ///
+    #[cfg(any(feature = "repr-array", feature = "repr-dump", feature = "repr-string"))]
+    pub(crate) fn parse_byte(&self, input: &str, value: &str) -> Result<u8, ParseError> {
+        u8::from_str_radix(value, self.radix()).map_err(|source| {
+            parse_error(source).at(
+                input,
+                offset_in(input, value),
+                value.len(),
+                Some(self.expected_byte()),
+            )
+        })
+    }
+    ///
     /// Return the maximum number of digits required for the given radix.
     ///
     pub fn max_width(&self) -> usize {
@@ -364,6 +1283,13 @@ pub const fn has_color() -> bool {
 #[cfg(not(feature = "repr-color"))]
 pub mod color {
     use crate::repr::{ByteKind, ReprComponentKind};
+    #[cfg(any(
+        feature = "repr-array",
+        feature = "repr-dump",
+        feature = "repr-escaped",
+        feature = "repr-string"
+    ))]
+    use crate::repr::{Marker, Markup};
 
     pub type Style = str;
 
@@ -373,8 +1299,8 @@ pub mod color {
             ""
         }
         #[inline(always)]
-        pub const fn byte_to_style(_: u8) -> Self {
-            Self::Printable
+        pub const fn byte_style(byte: u8) -> Self {
+            Self::from_byte(byte)
         }
         #[inline(always)]
         pub const fn ascii_char_display_style(_: &u8, _: bool) -> &'static Style {
@@ -388,17 +1314,74 @@ pub mod color {
             ""
         }
     }
+
+    ///
+    /// Without color there is nothing to style, a theme is only a placeholder.
+    ///
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct Theme {}
+
+    impl Theme {
+        #[cfg(any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        ))]
+        #[cfg_attr(not(feature = "repr-html"), allow(unused_variables))]
+        pub(crate) fn display_style(&self, kind: ReprComponentKind, markup: Markup) -> Marker {
+            match markup {
+                Markup::None => Marker::None,
+                #[cfg(feature = "repr-html")]
+                Markup::Html => Marker::Html(kind, None),
+            }
+        }
+        #[cfg(any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        ))]
+        pub(crate) fn byte_display_style(&self, byte: &u8, markup: Markup) -> Marker {
+            self.display_style(
+                ReprComponentKind::Value(ByteKind::byte_style(*byte)),
+                markup,
+            )
+        }
+    }
 }
 
 #[doc(hidden)]
 #[cfg(feature = "repr-color")]
 pub mod color {
     use crate::repr::{ByteKind, ReprComponentKind};
-    use anstyle::{AnsiColor, Color};
-    use core::option::Option::Some;
+    #[cfg(any(
+        feature = "repr-array",
+        feature = "repr-dump",
+        feature = "repr-escaped",
+        feature = "repr-string"
+    ))]
+    use crate::repr::{Marker, Markup};
+    use alloc::string::String;
+    use anstyle::{Ansi256Color, AnsiColor, Color, RgbColor};
+    use core::{
+        clone::Clone,
+        cmp::{Eq, PartialEq},
+        convert::From,
+        default::Default,
+        fmt::Debug,
+        marker::Copy,
+        option::Option::Some,
+    };
 
     pub use anstyle::Style;
 
+    #[cfg(feature = "std")]
+    use std::{
+        env,
+        io::{self, IsTerminal},
+    };
+
     // ------------------------------------------------------------------------------------------------
     // Color/Styles
     // ------------------------------------------------------------------------------------------------
@@ -409,6 +1392,10 @@ pub mod color {
     const DELIMITER_STYLE: Style = Style::new().dimmed();
     const SEPARATOR_STYLE: Style = Style::new().dimmed();
     const INDEX_STYLE: Style = Style::new().dimmed();
+    const DIFFERENCE_STYLE: Style = Style::new()
+        .fg_color(Some(Color::Ansi(AnsiColor::BrightWhite)))
+        .bg_color(Some(Color::Ansi(AnsiColor::Red)))
+        .bold();
 
     const ASCII_CONTROL: Style = Style::new()
         .fg_color(Some(Color::Ansi(AnsiColor::BrightRed)))
@@ -419,10 +1406,447 @@ pub mod color {
     const ASCII_8BIT_PRINTABLE: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Green)));
     const ASCII_8BIT_UNDEFINED: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Yellow)));
 
+    // --------------------------------------------------------------------------------------------
+    // Public Types
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// A mapping from each [`ReprComponentKind`], and each [`ByteKind`] of data value, to the
+    /// style used to display it. The default theme is intended for terminals with a dark
+    /// background.
+    ///
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Theme {
+        prefix: Style,
+        delimiter: Style,
+        separator: Style,
+        index: Style,
+        difference: Style,
+        control: Style,
+        printable: Style,
+        printable_extended: Style,
+        undefined: Style,
+        byte_styling: ByteStyling,
+    }
+
+    ///
+    /// The strategy used to choose the style of each data value of a representation.
+    ///
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum ByteStyling {
+        /// Style each byte with the theme's style for its [`ByteKind`].
+        #[default]
+        Kind,
+        /// Color each byte by its value, on a gradient of 256 steps from `0x00` to `0xFF`.
+        Gradient(ColorDepth),
+        /// Color each byte by its [`ByteCategory`].
+        Category(ColorDepth),
+    }
+
+    ///
+    /// The colors emitted by the [`ByteStyling::Gradient`] and [`ByteStyling::Category`]
+    /// strategies.
+    ///
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ColorDepth {
+        /// 8-bit colors, from the 256 color palette.
+        Ansi256,
+        /// 24-bit RGB colors.
+        TrueColor,
+    }
+
+    ///
+    /// A classification of bytes for spotting structure in binary data, as used by the
+    /// [`ByteStyling::Category`] strategy.
+    ///
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ByteCategory {
+        /// The byte `0x00`.
+        Null,
+        /// An ASCII whitespace character; space, tab, line feed, form feed or carriage return.
+        Whitespace,
+        /// An ASCII printable, non-whitespace, character.
+        Printable,
+        /// Any other ASCII byte, that is a control character other than whitespace or null.
+        OtherAscii,
+        /// A byte outside the ASCII range, other than `0xFF`.
+        NonAscii,
+        /// The byte `0xFF`.
+        Full,
+    }
+
+    ///
+    /// When to use color in a representation.
+    ///
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ColorPolicy {
+        /// Always use color.
+        Always,
+        /// Never use color.
+        Never,
+        /// Use color if the environment allows it, see [`ColorPolicy::use_color_with`], and the
+        /// stream the representation is written to is a terminal. Without the `std` feature
+        /// there is neither environment nor terminal to check, and color is never used.
+        Auto(ColorStream),
+    }
+
+    ///
+    /// The stream checked by [`ColorPolicy::Auto`] to determine whether it is a terminal.
+    ///
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum ColorStream {
+        #[default]
+        Stdout,
+        Stderr,
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Private Functions
+    // --------------------------------------------------------------------------------------------
+
+    // The stops of the gradient, evenly spaced over the byte values, running from dark purple
+    // through blue and green to yellow so that low and high values are easily told apart.
+    const GRADIENT_STOPS: [(u8, u8, u8); 5] = [
+        (68, 1, 84),
+        (59, 82, 139),
+        (33, 145, 140),
+        (94, 201, 98),
+        (253, 231, 37),
+    ];
+
+    fn gradient_rgb(byte: u8) -> (u8, u8, u8) {
+        let segments = GRADIENT_STOPS.len() - 1;
+        let position = usize::from(byte) * segments;
+        let (index, step) = (position / 255, position % 255);
+        if index == segments {
+            return GRADIENT_STOPS[segments];
+        }
+        let (from, to) = (GRADIENT_STOPS[index], GRADIENT_STOPS[index + 1]);
+        let mix =
+            |a: u8, b: u8| (i32::from(a) + (i32::from(b) - i32::from(a)) * step as i32 / 255) as u8;
+        (mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+
     // --------------------------------------------------------------------------------------------
     // Implementations
     // --------------------------------------------------------------------------------------------
 
+    impl Default for ColorPolicy {
+        fn default() -> Self {
+            Self::Auto(ColorStream::default())
+        }
+    }
+
+    impl ColorPolicy {
+        ///
+        /// Returns `true` if color is to be used, for [`Self::Auto`] this reads the environment
+        /// variables `NO_COLOR`, `CLICOLOR_FORCE` and `CLICOLOR` and checks the stream.
+        ///
+        pub fn use_color(&self) -> bool {
+            match self {
+                Self::Always => true,
+                Self::Never => false,
+                #[cfg(feature = "std")]
+                Self::Auto(stream) => self.use_color_for(match stream {
+                    ColorStream::Stdout => io::stdout().is_terminal(),
+                    ColorStream::Stderr => io::stderr().is_terminal(),
+                }),
+                #[cfg(not(feature = "std"))]
+                Self::Auto(_) => false,
+            }
+        }
+
+        ///
+        /// Returns `true` if color is to be used writing to a stream that is, or is not, a
+        /// terminal; as [`Self::use_color`] but for that stream rather than the one named by
+        /// [`Self::Auto`].
+        ///
+        #[cfg(feature = "std")]
+        pub fn use_color_for(&self, is_terminal: bool) -> bool {
+            self.use_color_with(
+                |name| env::var_os(name).map(|value| value.to_string_lossy().into_owned()),
+                is_terminal,
+            )
+        }
+
+        ///
+        /// Returns `true` if color is to be used, where `var` returns the value of an
+        /// environment variable, if set, and `is_terminal` is whether the stream is a terminal.
+        /// For [`Self::Auto`] the following rules apply, in order:
+        ///
+        /// 1. if `NO_COLOR` is set, and not empty, color is not used,
+        /// 2. if `CLICOLOR_FORCE` is set, and not `0`, color is used,
+        /// 3. if `CLICOLOR` is `0` color is not used,
+        /// 4. otherwise, color is used only if the stream is a terminal.
+        ///
+        pub fn use_color_with<F>(&self, var: F, is_terminal: bool) -> bool
+        where
+            F: Fn(&str) -> Option<String>,
+        {
+            match self {
+                Self::Always => true,
+                Self::Never => false,
+                Self::Auto(_) => {
+                    if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+                        false
+                    } else if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                        true
+                    } else if var("CLICOLOR").is_some_and(|value| value == "0") {
+                        false
+                    } else {
+                        is_terminal
+                    }
+                }
+            }
+        }
+    }
+
+    impl From<bool> for ColorPolicy {
+        fn from(colored: bool) -> Self {
+            if colored {
+                Self::Always
+            } else {
+                Self::Never
+            }
+        }
+    }
+
+    impl Default for Theme {
+        fn default() -> Self {
+            Self {
+                prefix: PREFIX_STYLE,
+                delimiter: DELIMITER_STYLE,
+                separator: SEPARATOR_STYLE,
+                index: INDEX_STYLE,
+                difference: DIFFERENCE_STYLE,
+                control: ASCII_CONTROL,
+                printable: ASCII_7BIT_PRINTABLE,
+                printable_extended: ASCII_8BIT_PRINTABLE,
+                undefined: ASCII_8BIT_UNDEFINED,
+                byte_styling: ByteStyling::Kind,
+            }
+        }
+    }
+
+    impl Theme {
+        ///
+        /// A theme for terminals with a light background, avoiding the bright colors and dimmed
+        /// text that are hard to read on one.
+        ///
+        pub fn light() -> Self {
+            let muted = Style::new().fg_color(Some(Color::Ansi(AnsiColor::BrightBlack)));
+            Self {
+                prefix: PREFIX_STYLE,
+                delimiter: muted,
+                separator: muted,
+                index: muted,
+                difference: DIFFERENCE_STYLE,
+                control: Style::new()
+                    .fg_color(Some(Color::Ansi(AnsiColor::Red)))
+                    .bold(),
+                printable: Style::new()
+                    .fg_color(Some(Color::Ansi(AnsiColor::Blue)))
+                    .bold(),
+                printable_extended: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Blue))),
+                undefined: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Magenta))),
+                byte_styling: ByteStyling::Kind,
+            }
+        }
+
+        ///
+        /// A theme using only bold, bright, colors and no dimmed text.
+        ///
+        pub fn high_contrast() -> Self {
+            let bright = |color| Style::new().fg_color(Some(Color::Ansi(color))).bold();
+            Self {
+                prefix: Style::new().bold(),
+                delimiter: bright(AnsiColor::BrightWhite),
+                separator: bright(AnsiColor::BrightWhite),
+                index: bright(AnsiColor::BrightWhite),
+                difference: Style::new()
+                    .fg_color(Some(Color::Ansi(AnsiColor::Black)))
+                    .bg_color(Some(Color::Ansi(AnsiColor::BrightYellow)))
+                    .bold(),
+                control: bright(AnsiColor::BrightRed),
+                printable: bright(AnsiColor::BrightGreen),
+                printable_extended: bright(AnsiColor::BrightCyan),
+                undefined: bright(AnsiColor::BrightYellow),
+                byte_styling: ByteStyling::Kind,
+            }
+        }
+
+        ///
+        /// A theme using no colors at all, only effects such as bold and underline, for
+        /// monochrome terminals and output that may be printed.
+        ///
+        pub fn monochrome_bold() -> Self {
+            Self {
+                prefix: Style::new(),
+                delimiter: Style::new().dimmed(),
+                separator: Style::new().dimmed(),
+                index: Style::new().dimmed(),
+                difference: Style::new().invert().bold(),
+                control: Style::new().underline(),
+                printable: Style::new().bold(),
+                printable_extended: Style::new(),
+                undefined: Style::new().dimmed(),
+                byte_styling: ByteStyling::Kind,
+            }
+        }
+
+        /// Returns the style of the component kind, for [`ReprComponentKind::Value`] this is
+        /// the style of its byte kind.
+        pub fn component_style(&self, kind: ReprComponentKind) -> Style {
+            match kind {
+                ReprComponentKind::Prefix => self.prefix,
+                ReprComponentKind::Delimiter => self.delimiter,
+                ReprComponentKind::Separator => self.separator,
+                ReprComponentKind::Index => self.index,
+                ReprComponentKind::Difference => self.difference,
+                ReprComponentKind::Value(kind) => self.byte_kind_style(kind),
+            }
+        }
+
+        /// Returns the style of data values of the byte kind.
+        pub fn byte_kind_style(&self, kind: ByteKind) -> Style {
+            match kind {
+                ByteKind::Control => self.control,
+                ByteKind::Printable => self.printable,
+                ByteKind::PrintableExtended => self.printable_extended,
+                ByteKind::Undefined => self.undefined,
+            }
+        }
+
+        /// Returns the style of a data value, according to the byte styling strategy.
+        pub fn byte_style(&self, byte: u8) -> Style {
+            let (depth, (r, g, b)) = match self.byte_styling {
+                ByteStyling::Kind => return self.byte_kind_style(ByteKind::byte_style(byte)),
+                ByteStyling::Gradient(depth) => (depth, gradient_rgb(byte)),
+                ByteStyling::Category(depth) => (depth, ByteCategory::from_byte(byte).rgb()),
+            };
+            Style::new().fg_color(Some(depth.color(r, g, b)))
+        }
+
+        /// Returns the strategy used to style data values.
+        pub fn byte_styling(&self) -> ByteStyling {
+            self.byte_styling
+        }
+
+        /// Sets the strategy used to style data values, the default is [`ByteStyling::Kind`].
+        pub fn with_byte_styling(mut self, byte_styling: ByteStyling) -> Self {
+            self.byte_styling = byte_styling;
+            self
+        }
+
+        /// Sets the style of the component kind, for [`ReprComponentKind::Value`] this sets the
+        /// style of its byte kind.
+        pub fn with_component_style(mut self, kind: ReprComponentKind, style: Style) -> Self {
+            match kind {
+                ReprComponentKind::Prefix => self.prefix = style,
+                ReprComponentKind::Delimiter => self.delimiter = style,
+                ReprComponentKind::Separator => self.separator = style,
+                ReprComponentKind::Index => self.index = style,
+                ReprComponentKind::Difference => self.difference = style,
+                ReprComponentKind::Value(kind) => return self.with_byte_kind_style(kind, style),
+            }
+            self
+        }
+
+        /// Sets the style of data values of the byte kind.
+        pub fn with_byte_kind_style(mut self, kind: ByteKind, style: Style) -> Self {
+            match kind {
+                ByteKind::Control => self.control = style,
+                ByteKind::Printable => self.printable = style,
+                ByteKind::PrintableExtended => self.printable_extended = style,
+                ByteKind::Undefined => self.undefined = style,
+            }
+            self
+        }
+
+        #[cfg(any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        ))]
+        pub(crate) fn display_style(&self, kind: ReprComponentKind, markup: Markup) -> Marker {
+            match markup {
+                Markup::None => Marker::None,
+                // Automatic markup is resolved before writing, it is unstyled if it is not.
+                #[cfg(any(
+                    feature = "repr-array",
+                    feature = "repr-dump",
+                    feature = "repr-escaped",
+                    feature = "repr-string"
+                ))]
+                Markup::Auto(_) => Marker::None,
+                Markup::Ansi => Marker::Ansi(self.component_style(kind)),
+                #[cfg(feature = "repr-html")]
+                Markup::Html => Marker::Html(kind, None),
+            }
+        }
+
+        #[cfg(any(
+            feature = "repr-array",
+            feature = "repr-dump",
+            feature = "repr-escaped",
+            feature = "repr-string"
+        ))]
+        pub(crate) fn byte_display_style(&self, byte: &u8, markup: Markup) -> Marker {
+            match markup {
+                Markup::Ansi => Marker::Ansi(self.byte_style(*byte)),
+                _ => self.display_style(
+                    ReprComponentKind::Value(ByteKind::byte_style(*byte)),
+                    markup,
+                ),
+            }
+        }
+    }
+
+    impl ColorDepth {
+        /// Returns the color, at this depth, closest to the RGB color.
+        pub fn color(&self, r: u8, g: u8, b: u8) -> Color {
+            match self {
+                Self::Ansi256 => {
+                    // The 6x6x6 color cube of the palette starts at index 16, with the levels
+                    // 0, 95, 135, 175, 215 and 255 in each component.
+                    let level = |c: u8| match c {
+                        0..=47 => 0,
+                        48..=114 => 1,
+                        _ => (c - 35) / 40,
+                    };
+                    Color::Ansi256(Ansi256Color(16 + 36 * level(r) + 6 * level(g) + level(b)))
+                }
+                Self::TrueColor => Color::Rgb(RgbColor(r, g, b)),
+            }
+        }
+    }
+
+    impl ByteCategory {
+        /// Returns the category of the byte.
+        pub const fn from_byte(byte: u8) -> Self {
+            match byte {
+                0x00 => Self::Null,
+                b' ' | b'\t' | b'\n' | b'\x0C' | b'\r' => Self::Whitespace,
+                0x21..=0x7E => Self::Printable,
+                0x01..=0x7F => Self::OtherAscii,
+                0xFF => Self::Full,
+                0x80..=0xFE => Self::NonAscii,
+            }
+        }
+
+        const fn rgb(&self) -> (u8, u8, u8) {
+            match self {
+                Self::Null => (135, 135, 135),
+                Self::Whitespace => (95, 215, 135),
+                Self::Printable => (0, 175, 215),
+                Self::OtherAscii => (175, 95, 255),
+                Self::NonAscii => (255, 175, 0),
+                Self::Full => (255, 255, 255),
+            }
+        }
+    }
+
     impl ByteKind {
         pub const fn display_style(&self, colored: bool) -> &'static Style {
             if !colored {
@@ -443,16 +1867,7 @@ pub mod color {
 
         #[allow(clippy::self_named_constructors)]
         pub const fn byte_style(byte: u8) -> Self {
-            match byte {
-                0x00..=0x20 => Self::Control,
-                0x21..=0x7E => Self::Printable,
-                0x7F => Self::Control,
-                0x80..=0x9F => Self::Undefined,
-                0xA0 => Self::Control,
-                0xA1..=0xAC => Self::PrintableExtended,
-                0xAD => Self::Control,
-                0xAE..=0xFF => Self::PrintableExtended,
-            }
+            Self::from_byte(byte)
         }
     }
 
@@ -466,6 +1881,7 @@ pub mod color {
                     Self::Delimiter => &DELIMITER_STYLE,
                     Self::Separator => &SEPARATOR_STYLE,
                     Self::Index => &INDEX_STYLE,
+                    Self::Difference => &DIFFERENCE_STYLE,
                     Self::Value(v) => v.display_style(colored),
                 }
             }
@@ -476,19 +1892,83 @@ pub mod color {
 #[cfg(feature = "repr-array")]
 pub mod array;
 #[cfg(feature = "repr-array")]
-use crate::repr::array::{array_representation, ArrayFormatOptions};
+use crate::repr::array::{
+    parse_array_representation, write_array_representation, ArrayFormatOptions,
+};
+
+#[cfg(feature = "repr-ascii85")]
+pub mod ascii85;
+#[cfg(feature = "repr-ascii85")]
+use crate::repr::ascii85::{
+    parse_ascii85_representation_with, write_ascii85_representation, Ascii85FormatOptions,
+};
+
+#[cfg(feature = "repr-base32")]
+pub mod base32;
+#[cfg(feature = "repr-base32")]
+use crate::repr::base32::{
+    parse_base32_representation_with, write_base32_representation, Base32FormatOptions,
+};
+
+#[cfg(feature = "repr-base58")]
+pub mod base58;
+#[cfg(feature = "repr-base58")]
+use crate::repr::base58::{
+    parse_base58_representation_with, write_base58_representation, Base58FormatOptions,
+};
 
 #[cfg(feature = "repr-base64")]
 pub mod base64;
 #[cfg(feature = "repr-base64")]
-use crate::repr::base64::{base64_representation, Base64FormatOptions};
+use crate::repr::base64::{
+    detect_base64_options, parse_base64_representation_with, write_base64_representation,
+    Base64FormatOptions,
+};
 
 #[cfg(feature = "repr-dump")]
 pub mod dump;
 #[cfg(feature = "repr-dump")]
-use crate::repr::dump::{dump_representation, DumpFormatOptions};
+use crate::repr::dump::{
+    detect_dump_options, parse_dump_representation, write_dump_representation, DumpFormatOptions,
+};
+
+#[cfg(feature = "repr-escaped")]
+pub mod escaped;
+#[cfg(feature = "repr-escaped")]
+use crate::repr::escaped::{
+    detect_escaped_options, parse_escaped_representation, write_escaped_representation,
+    EscapedFormatOptions,
+};
+
+#[cfg(feature = "repr-html")]
+pub mod html;
+
+#[cfg(feature = "repr-intel-hex")]
+pub mod intel_hex;
+#[cfg(feature = "repr-intel-hex")]
+use crate::repr::intel_hex::{
+    detect_intel_hex_options, parse_intel_hex_representation, write_intel_hex_representation,
+    IntelHexFormatOptions,
+};
+
+#[cfg(feature = "repr-markdown")]
+pub mod markdown;
+
+#[cfg(feature = "repr-source")]
+pub mod source;
+#[cfg(feature = "repr-source")]
+use crate::repr::source::{write_source_representation, SourceFormatOptions};
+
+#[cfg(feature = "repr-srec")]
+pub mod srec;
+#[cfg(feature = "repr-srec")]
+use crate::repr::srec::{
+    detect_srec_options, parse_srec_representation, write_srec_representation, SRecordFormatOptions,
+};
 
 #[cfg(feature = "repr-string")]
 pub mod string;
 #[cfg(feature = "repr-string")]
-use crate::repr::string::{string_representation, StringFormatOptions};
+use crate::repr::string::{
+    parse_string_representation, write_string_representation, StringFormatOptions,
+};
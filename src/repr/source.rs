@@ -0,0 +1,308 @@
+//!
+//! A source code representation of binary data, as a declaration that may be pasted into a
+//! program in one of several languages; similar to the output of `xxd -i`.
+//!
+//! | Language   | Declaration                                                   |
+//! |------------|---------------------------------------------------------------|
+//! | Rust       | `const NAME: [u8; N] = [...];`                                |
+//! | C          | `unsigned char name[] = {...};` `unsigned int name_len = N;`  |
+//! | Python     | `name = bytes([...])`                                         |
+//! | Go         | `var name = []byte{...}`                                      |
+//! | JavaScript | `const name = new Uint8Array([...]);`                         |
+//!
+//! Bytes are formatted with [`RadixFormat::format`] and given the literal prefix of the
+//! selected language; decimal bytes are padded with spaces rather than zeros, as a leading zero
+//! denotes an octal literal, or is an error, in most of these languages. Every line of bytes ends
+//! with a comma, which all of the languages allow and Go requires. C has no binary literals
+//! before C23, so binary bytes are written in hexadecimal, and no zero-length arrays, so an empty
+//! value is declared as a single zero byte with a length of zero.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-source"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-source", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, format, source::SourceFormatOptions}
+//! };
+//!
+//! let binary = Binary::from([
+//!     0x7b_u8,0xe6_u8,0xd4_u8,0xf2_u8,0x25_u8,0x5c_u8,0x62_u8,0xd3_u8,
+//!     0x21_u8,0x24_u8,0xab_u8,0x7e_u8,0x40_u8,0xf1_u8,0x7b_u8,0xce_u8,
+//! ]);
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         SourceFormatOptions::default().with_bytes_per_line(8)),
+//!     vec![
+//!         "const DATA: [u8; 16] = [",
+//!         "    0x7b, 0xe6, 0xd4, 0xf2, 0x25, 0x5c, 0x62, 0xd3,",
+//!         "    0x21, 0x24, 0xab, 0x7e, 0x40, 0xf1, 0x7b, 0xce,",
+//!         "];",
+//!     ].join("\n")
+//! );
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         SourceFormatOptions::default()
+//!             .with_c_language()
+//!             .with_name("packet")
+//!             .with_indent("  ")),
+//!     vec![
+//!         "unsigned char packet[] = {",
+//!         "  0x7b, 0xe6, 0xd4, 0xf2, 0x25, 0x5c, 0x62, 0xd3, 0x21, 0x24, 0xab, 0x7e,",
+//!         "  0x40, 0xf1, 0x7b, 0xce,",
+//!         "};",
+//!         "unsigned int packet_len = 16;",
+//!     ].join("\n")
+//! );
+//! ```
+//!
+
+use crate::{
+    repr::{format_with, BinaryFormatOptions, RadixFormat},
+    Binary,
+};
+use alloc::string::{String, ToString};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    convert::Into,
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{self, None, Some},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceFormatOptions {
+    language: SourceLanguage,
+    name: Option<String>,
+    radix_format: RadixFormat,
+    compact: bool,
+    bytes_per_line: usize,
+    indent: String,
+}
+
+///
+/// The programming language to write the declaration in.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SourceLanguage {
+    /// A Rust `const` array of `u8`, the default name is `DATA`.
+    #[default]
+    Rust,
+    /// A C `unsigned char` array and `unsigned int` length, the default name is `data`. Binary
+    /// bytes are written in hexadecimal, and an empty value as an array of one zero byte.
+    C,
+    /// A Python `bytes` value, the default name is `data`.
+    Python,
+    /// A Go `[]byte` slice, the default name is `data`.
+    Go,
+    /// A JavaScript `Uint8Array`, the default name is `data`.
+    JavaScript,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub fn source_representation(value: &Binary<'_>, options: &SourceFormatOptions) -> String {
+    format_with(value.len() * 6 + 64, |buffer| {
+        write_source_representation(buffer, value, options)
+    })
+}
+
+///
+/// Write `value` as a source code declaration to `w`, see [`source_representation`].
+///
+pub fn write_source_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &SourceFormatOptions,
+) -> fmt::Result {
+    let language = options.language;
+    let name = options
+        .name
+        .as_deref()
+        .unwrap_or_else(|| language.default_name());
+    match language {
+        SourceLanguage::Rust => write!(w, "const {name}: [u8; {}] = [", value.len())?,
+        SourceLanguage::C if value.is_empty() => write!(w, "unsigned char {name}[1] = {{0")?,
+        SourceLanguage::C => write!(w, "unsigned char {name}[] = {{")?,
+        SourceLanguage::Python => write!(w, "{name} = bytes([")?,
+        SourceLanguage::Go => write!(w, "var {name} = []byte{{")?,
+        SourceLanguage::JavaScript => write!(w, "const {name} = new Uint8Array([")?,
+    }
+    let separator = if options.compact { "," } else { ", " };
+
+    for bytes in value.chunks(options.bytes_per_line) {
+        write!(w, "\n{}", options.indent)?;
+        for (i, b) in bytes.iter().enumerate() {
+            if i > 0 {
+                w.write_str(separator)?;
+            }
+            options.write_byte(w, b)?;
+        }
+        w.write_char(',')?;
+    }
+    if !value.is_empty() {
+        w.write_char('\n')?;
+    }
+    match language {
+        SourceLanguage::Rust => w.write_str("];"),
+        SourceLanguage::C => write!(w, "}};\nunsigned int {name}_len = {};", value.len()),
+        SourceLanguage::Python => w.write_str("])"),
+        SourceLanguage::Go => w.write_char('}'),
+        SourceLanguage::JavaScript => w.write_str("]);"),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<SourceFormatOptions> for BinaryFormatOptions {
+    fn from(value: SourceFormatOptions) -> Self {
+        Self::Source(value)
+    }
+}
+
+impl Default for SourceFormatOptions {
+    fn default() -> Self {
+        Self {
+            language: SourceLanguage::default(),
+            name: None,
+            radix_format: RadixFormat::LowerHex,
+            compact: false,
+            bytes_per_line: 12,
+            indent: "    ".to_string(),
+        }
+    }
+}
+
+impl SourceFormatOptions {
+    /// Sets the language of the declaration to be one of the values of the enum
+    /// [`SourceLanguage`].
+    pub fn with_language(mut self, language: SourceLanguage) -> Self {
+        self.language = language;
+        self
+    }
+    /// Sets the language of the declaration to [`SourceLanguage::Rust`].
+    pub fn with_rust_language(self) -> Self {
+        Self::with_language(self, SourceLanguage::Rust)
+    }
+    /// Sets the language of the declaration to [`SourceLanguage::C`].
+    pub fn with_c_language(self) -> Self {
+        Self::with_language(self, SourceLanguage::C)
+    }
+    /// Sets the language of the declaration to [`SourceLanguage::Python`].
+    pub fn with_python_language(self) -> Self {
+        Self::with_language(self, SourceLanguage::Python)
+    }
+    /// Sets the language of the declaration to [`SourceLanguage::Go`].
+    pub fn with_go_language(self) -> Self {
+        Self::with_language(self, SourceLanguage::Go)
+    }
+    /// Sets the language of the declaration to [`SourceLanguage::JavaScript`].
+    pub fn with_javascript_language(self) -> Self {
+        Self::with_language(self, SourceLanguage::JavaScript)
+    }
+
+    /// Sets the identifier declared, replacing the default name for the language.
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the radix format for each byte to be one of the values of the enum
+    /// [`RadixFormat`]; the default is [`RadixFormat::LowerHex`].
+    pub fn with_byte_radix_format(mut self, radix_format: RadixFormat) -> Self {
+        self.radix_format = radix_format;
+        self
+    }
+    /// Sets the radix format for each byte to [`RadixFormat::Binary`]; for C, which has no
+    /// binary literals, bytes are written as [`RadixFormat::LowerHex`].
+    pub fn with_binary_bytes(self) -> Self {
+        Self::with_byte_radix_format(self, RadixFormat::Binary)
+    }
+    /// Sets the radix format for each byte to [`RadixFormat::Decimal`].
+    pub fn with_decimal_bytes(self) -> Self {
+        Self::with_byte_radix_format(self, RadixFormat::Decimal)
+    }
+    /// Sets the radix format for each byte to [`RadixFormat::LowerHex`].
+    pub fn with_lower_hex_bytes(self) -> Self {
+        Self::with_byte_radix_format(self, RadixFormat::LowerHex)
+    }
+    /// Sets the radix format for each byte to [`RadixFormat::Octal`].
+    pub fn with_octal_bytes(self) -> Self {
+        Self::with_byte_radix_format(self, RadixFormat::Octal)
+    }
+    /// Sets the radix format for each byte to [`RadixFormat::UpperHex`].
+    pub fn with_upper_hex_bytes(self) -> Self {
+        Self::with_byte_radix_format(self, RadixFormat::UpperHex)
+    }
+
+    /// Use a compact representation, this removes the space after commas and any leading zeros
+    /// or padding from bytes.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the number of bytes on each line, at least one; the default is 12.
+    pub fn with_bytes_per_line(mut self, bytes_per_line: usize) -> Self {
+        self.bytes_per_line = bytes_per_line.max(1);
+        self
+    }
+
+    /// Sets the string used to indent each line of bytes; the default is four spaces.
+    pub fn with_indent<S: Into<String>>(mut self, indent: S) -> Self {
+        self.indent = indent.into();
+        self
+    }
+
+    fn write_byte<W: Write + ?Sized>(&self, w: &mut W, byte: &u8) -> fmt::Result {
+        match (self.language.radix_format(self.radix_format), self.compact) {
+            (RadixFormat::Decimal, true) => self.radix_format.write(w, byte, true),
+            (RadixFormat::Decimal, false) => write!(w, "{byte:>3}"),
+            (radix_format, compact) => {
+                w.write_str(self.language.radix_prefix(radix_format))?;
+                radix_format.write(w, byte, compact)
+            }
+        }
+    }
+}
+
+impl SourceLanguage {
+    const fn default_name(&self) -> &'static str {
+        match self {
+            Self::Rust => "DATA",
+            _ => "data",
+        }
+    }
+
+    const fn radix_format(&self, radix_format: RadixFormat) -> RadixFormat {
+        match (self, radix_format) {
+            (Self::C, RadixFormat::Binary) => RadixFormat::LowerHex,
+            (_, radix_format) => radix_format,
+        }
+    }
+
+    const fn radix_prefix(&self, radix_format: RadixFormat) -> &'static str {
+        match (self, radix_format) {
+            (_, RadixFormat::Decimal) => "",
+            (_, RadixFormat::Binary) => "0b",
+            (Self::C, RadixFormat::Octal) => "0",
+            (_, RadixFormat::Octal) => "0o",
+            (_, RadixFormat::LowerHex | RadixFormat::UpperHex) => "0x",
+        }
+    }
+}
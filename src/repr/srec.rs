@@ -0,0 +1,481 @@
+//!
+//! A [Motorola S-record](https://en.wikipedia.org/wiki/SREC_(file_format)) (SREC)
+//! representation of binary data, as used to load firmware images into microcontrollers and
+//! EPROM programmers.
+//!
+//! ```ebnf
+//! SRecordRepresentation ::= HeaderRecord '\n' { DataRecord '\n' } CountRecord '\n'
+//!                           TerminationRecord '\n'
+//!
+//! HeaderRecord ::= 'S0' ByteCount '0000' { Byte } Checksum
+//! DataRecord ::= ( 'S1' ByteCount Byte{2} | 'S2' ByteCount Byte{3} | 'S3' ByteCount Byte{4} )
+//!                { Byte } Checksum
+//! CountRecord ::= ( 'S5' ByteCount Byte{2} | 'S6' ByteCount Byte{3} ) Checksum
+//! TerminationRecord ::= ( 'S9' ByteCount Byte{2} | 'S8' ByteCount Byte{3} |
+//!                         'S7' ByteCount Byte{4} ) Checksum
+//!
+//! ByteCount ::= Byte
+//! Checksum ::= Byte
+//! Byte ::= Nybble Nybble
+//! Nybble ::= [0-9a-fA-F]
+//! ```
+//!
+//! Each data record holds at most 16 bytes. The width of addresses, and so the data and
+//! termination record types, is either chosen to fit the highest address or set explicitly; an
+//! explicit width too narrow for the addresses is widened. The count record is `S5` unless there
+//! are more than 65,535 data records, and `S6` unless there are more than 16,777,215, in which
+//! case it is left out as count records are optional. A value that extends past the 32-bit
+//! address space, or a header longer than the 252 bytes a record can hold, cannot be written.
+//!
+//! When parsing, each record's checksum is verified and errors report the, one-based, line number
+//! of the record. A count record, if present, must match the number of data records and a
+//! termination record is required. Data records need not be contiguous, the resulting value
+//! starts at the lowest address and any gaps between records are filled with `0xFF`; gaps that
+//! add up to more than 16M are an error rather than a value mostly made of fill.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "repr-srec"), doc = "```ignore")]
+#![cfg_attr(feature = "repr-srec", doc = "```rust")]
+//! use wrapbin::{
+//!     Binary,
+//!     repr::{BinaryFormatOptions, format, srec::SRecordFormatOptions}
+//! };
+//!
+//! let binary = Binary::from([
+//!     0x7b_u8,0xe6_u8,0xd4_u8,0xf2_u8,0x25_u8,0x5c_u8,0x62_u8,0xd3_u8,
+//!     0x21_u8,0x24_u8,0xab_u8,0x7e_u8,0x40_u8,0xf1_u8,0x7b_u8,0xce_u8,
+//!     0x17_u8,0x3c_u8,0x08_u8,0xd2_u8,
+//! ]);
+//!
+//! assert_eq!(
+//!     format(
+//!         &binary,
+//!         SRecordFormatOptions::default().with_header("HDR").with_base_address(0x0100)),
+//!     vec![
+//!         "S00600004844521B",
+//!         "S11301007BE6D4F2255C62D32124AB7E40F17BCE26",
+//!         "S1070110173C08D2BA",
+//!         "S5030002FA",
+//!         "S9030000FC",
+//!         "",
+//!     ].join("\n")
+//! );
+//! ```
+//!
+
+use crate::{
+    error::{offset_in, Error, ParseError},
+    repr::{assemble_records, format_with, BinaryFormatOptions},
+    Binary,
+};
+use alloc::{string::String, vec::Vec};
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    default::Default,
+    fmt::{self, Debug, Write},
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    result::Result::{self, Err, Ok},
+};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SRecordFormatOptions {
+    header: String,
+    address_width: SRecordAddressWidth,
+    base_address: u32,
+    start_address: u32,
+}
+
+///
+/// The width of addresses, which determines the data and termination record types.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SRecordAddressWidth {
+    /// Use the narrowest width that fits the highest address.
+    #[default]
+    Auto,
+    /// 16-bit addresses, in `S1` data records and an `S9` termination record.
+    Bits16,
+    /// 24-bit addresses, in `S2` data records and an `S8` termination record.
+    Bits24,
+    /// 32-bit addresses, in `S3` data records and an `S7` termination record.
+    Bits32,
+}
+
+///
+/// The result of parsing an S-record representation, the data along with the address of its
+/// first byte, the header, and the start address from the termination record.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SRecordImage {
+    header: Vec<u8>,
+    base_address: u32,
+    start_address: u32,
+    binary: Binary<'static>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Format `value` as S-records.
+///
+/// # Panics
+///
+/// If `value` extends past the 32-bit address space, or the header is longer than a record can
+/// hold, see [`try_srec_representation`] to return an error instead.
+///
+pub fn srec_representation(value: &Binary<'_>, options: &SRecordFormatOptions) -> String {
+    format_with(
+        (value.len() / RECORD_LENGTH + 4) * (RECORD_LENGTH * 2 + 14),
+        |buffer| write_srec_representation(buffer, value, options),
+    )
+}
+
+///
+/// Write the representation of `value` to `w`, see [`srec_representation`].
+///
+pub fn write_srec_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &SRecordFormatOptions,
+) -> fmt::Result {
+    options.check_header_length().map_err(|_| fmt::Error)?;
+    options.check_address_range(value).map_err(|_| fmt::Error)?;
+    let end_address = value
+        .len()
+        .saturating_sub(1)
+        .saturating_add(options.base_address as usize);
+    let address_length = options
+        .address_width
+        .address_length()
+        .max(address_length_for(
+            end_address.max(options.start_address as usize),
+        ));
+    let (data_type, termination_type) = match address_length {
+        2 => ('1', '9'),
+        3 => ('2', '8'),
+        _ => ('3', '7'),
+    };
+
+    write_record(w, '0', 0, 2, options.header.as_bytes())?;
+    let mut count: u32 = 0;
+    let mut address = options.base_address;
+    for data in value.chunks(RECORD_LENGTH) {
+        write_record(w, data_type, address, address_length, data)?;
+        address = address.wrapping_add(data.len() as u32);
+        count += 1;
+    }
+    if count <= 0xFFFF {
+        write_record(w, '5', count, 2, &[])?;
+    } else if count <= 0xFF_FFFF {
+        write_record(w, '6', count, 3, &[])?;
+    }
+    write_record(
+        w,
+        termination_type,
+        options.start_address,
+        address_length,
+        &[],
+    )?;
+    Ok(())
+}
+
+///
+/// Format `value` as S-records, returning an error if `value` extends past the 32-bit address
+/// space or the header is longer than the 252 bytes a record can hold.
+///
+pub fn try_srec_representation(
+    value: &Binary<'_>,
+    options: &SRecordFormatOptions,
+) -> Result<String, Error> {
+    options.check_header_length()?;
+    options.check_address_range(value)?;
+    Ok(srec_representation(value, options))
+}
+
+///
+/// Parse an S-record representation, returning only the data; see [`parse_srec_image`] to also
+/// retrieve the header and addresses.
+///
+pub fn parse_srec_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    parse_srec_image(s).map(SRecordImage::into_binary)
+}
+
+///
+/// Parse an S-record representation, verifying the checksum of each record. Blank lines are
+/// ignored, a termination record is required, and no records may follow it.
+///
+pub fn parse_srec_image(s: &str) -> Result<SRecordImage, ParseError> {
+    let mut header = Vec::new();
+    let mut start_address = None;
+    let mut records: Vec<(u32, Vec<u8>, &str)> = Vec::new();
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid = |offset: usize, length: usize, expected| {
+            Error::InvalidRecord.at(s, offset_in(s, line) + offset, length, Some(expected))
+        };
+        if start_address.is_some() {
+            return Err(invalid(
+                0,
+                line.len(),
+                "no records after the termination record",
+            ));
+        }
+        let Some(record_type) = line
+            .strip_prefix('S')
+            .and_then(|record| record.chars().next())
+        else {
+            return Err(invalid(0, 2, "a record type: `S0` to `S9`"));
+        };
+        let address_length = match record_type {
+            '0' | '1' | '5' | '9' => 2,
+            '2' | '6' | '8' => 3,
+            '3' | '7' => 4,
+            _ => return Err(invalid(0, 2, "a record type: `S0` to `S9`")),
+        };
+        let (address, data) = parse_record(s, &line[2..], address_length)?;
+        match record_type {
+            '0' => header = data,
+            '1' | '2' | '3' => records.push((address, data, line)),
+            '5' | '6' if data.is_empty() && address as usize == records.len() => {}
+            '7' | '8' | '9' if data.is_empty() => start_address = Some(address),
+            _ => {
+                return Err(invalid(
+                    0,
+                    line.len(),
+                    "a record whose data and address match its type",
+                ))
+            }
+        }
+    }
+    let start_address = start_address
+        .ok_or_else(|| Error::InvalidRecord.at(s, s.len(), 0, Some("a termination record")))?;
+
+    let (base_address, values) = assemble_records(s, records)?;
+    Ok(SRecordImage {
+        header,
+        base_address,
+        start_address,
+        binary: Binary::from(values),
+    })
+}
+
+///
+/// Detect the options used to produce an S-record representation, returns `None` unless every
+/// non-blank line starts with `S` and a record type digit followed only by hex digits.
+///
+pub(crate) fn detect_srec_options(s: &str) -> Option<SRecordFormatOptions> {
+    let mut lines = s
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .peekable();
+    lines.peek()?;
+    let mut options = SRecordFormatOptions::default();
+    for line in lines {
+        let record = line.strip_prefix('S')?;
+        if record.len() < 9 || !record.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        match &record[..1] {
+            "1" => options = options.with_address_width(SRecordAddressWidth::Bits16),
+            "2" => options = options.with_address_width(SRecordAddressWidth::Bits24),
+            "3" => options = options.with_address_width(SRecordAddressWidth::Bits32),
+            _ => {}
+        }
+    }
+    Some(options)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const RECORD_LENGTH: usize = 16;
+
+/// The most bytes a header record can hold, after its count, address and checksum bytes.
+const MAX_HEADER_LENGTH: usize = 252;
+
+const fn address_length_for(address: usize) -> usize {
+    if address <= 0xFFFF {
+        2
+    } else if address <= 0xFF_FFFF {
+        3
+    } else {
+        4
+    }
+}
+
+fn write_record<W: Write + ?Sized>(
+    w: &mut W,
+    record_type: char,
+    address: u32,
+    address_length: usize,
+    data: &[u8],
+) -> fmt::Result {
+    let address = &address.to_be_bytes()[4 - address_length..];
+    let byte_count = [(address_length + data.len() + 1) as u8];
+    let sum = byte_count
+        .iter()
+        .chain(address.iter())
+        .chain(data.iter())
+        .fold(0_u8, |sum, byte| sum.wrapping_add(*byte));
+    write!(w, "S{record_type}")?;
+    for byte in byte_count.iter().chain(address.iter()).chain(data.iter()) {
+        write!(w, "{byte:02X}")?;
+    }
+    writeln!(w, "{:02X}", !sum)
+}
+
+///
+/// Parse the content of a single record, `record`, which is a slice of `s` following the record
+/// type; any error is located in `s`.
+///
+fn parse_record(
+    s: &str,
+    record: &str,
+    address_length: usize,
+) -> Result<(u32, Vec<u8>), ParseError> {
+    let start = offset_in(s, record);
+    let invalid = |offset: usize, length: usize, expected| {
+        Error::InvalidRecord.at(s, start + offset, length, Some(expected))
+    };
+    if !record.is_ascii() || !record.len().is_multiple_of(2) {
+        return Err(invalid(
+            0,
+            record.len(),
+            "a record of hexadecimal byte values",
+        ));
+    }
+    let bytes = (0..record.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&record[i..i + 2], 16)
+                .map_err(|_| invalid(i, 2, "a hexadecimal byte value"))
+        })
+        .collect::<Result<Vec<u8>, ParseError>>()?;
+    if bytes.len() < address_length + 2 || bytes[0] as usize != bytes.len() - 1 {
+        return Err(invalid(
+            0,
+            2,
+            "a byte count matching the length of the record",
+        ));
+    }
+    if bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte)) != 0xFF {
+        return Err(Error::RecordChecksumMismatch.at(
+            s,
+            start + record.len() - 2,
+            2,
+            Some("the checksum of the record"),
+        ));
+    }
+    let address = bytes[1..=address_length]
+        .iter()
+        .fold(0_u32, |address, byte| (address << 8) | u32::from(*byte));
+    Ok((address, bytes[address_length + 1..bytes.len() - 1].to_vec()))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<SRecordFormatOptions> for BinaryFormatOptions {
+    fn from(value: SRecordFormatOptions) -> Self {
+        Self::SRecord(value)
+    }
+}
+
+impl SRecordFormatOptions {
+    /// Sets the content of the `S0` header record, commonly a module or file name. A header
+    /// record holds at most 252 bytes, a longer header cannot be written.
+    pub fn with_header<S: Into<String>>(mut self, header: S) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Sets the width of addresses to be one of the values of the enum [`SRecordAddressWidth`].
+    pub fn with_address_width(mut self, address_width: SRecordAddressWidth) -> Self {
+        self.address_width = address_width;
+        self
+    }
+
+    /// Sets the address of the first byte of the value.
+    pub fn with_base_address(mut self, base_address: u32) -> Self {
+        self.base_address = base_address;
+        self
+    }
+
+    /// Sets the execution start address written in the termination record.
+    pub fn with_start_address(mut self, start_address: u32) -> Self {
+        self.start_address = start_address;
+        self
+    }
+
+    pub(crate) fn check_header_length(&self) -> Result<(), Error> {
+        match self.header.len() {
+            length if length > MAX_HEADER_LENGTH => Err(Error::HeaderTooLong {
+                length,
+                limit: MAX_HEADER_LENGTH,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_address_range(&self, value: &Binary<'_>) -> Result<(), Error> {
+        let limit = u64::from(u32::MAX);
+        match (u64::from(self.base_address) + value.len() as u64).checked_sub(1) {
+            Some(address) if address > limit => Err(Error::AddressOutOfRange { address, limit }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl SRecordAddressWidth {
+    /// Return the minimum number of bytes used for each address.
+    pub const fn address_length(&self) -> usize {
+        match self {
+            Self::Auto | Self::Bits16 => 2,
+            Self::Bits24 => 3,
+            Self::Bits32 => 4,
+        }
+    }
+}
+
+impl SRecordImage {
+    /// Return the content of the `S0` header record, empty if there was none.
+    pub fn header(&self) -> &[u8] {
+        &self.header
+    }
+
+    /// Return the address of the first byte of the data.
+    pub const fn base_address(&self) -> u32 {
+        self.base_address
+    }
+
+    /// Return the execution start address from the termination record.
+    pub const fn start_address(&self) -> u32 {
+        self.start_address
+    }
+
+    /// Return a reference to the data.
+    pub const fn binary(&self) -> &Binary<'static> {
+        &self.binary
+    }
+
+    /// Return the data, consuming the image.
+    pub fn into_binary(self) -> Binary<'static> {
+        self.binary
+    }
+}
@@ -82,21 +82,22 @@
 //! ```
 //!
 
+#[cfg(feature = "repr-color")]
+use crate::repr::color::ColorPolicy;
 use crate::{
-    error::Error,
-    repr::{BinaryFormatOptions, ByteKind, RadixFormat, ReprComponentKind},
+    error::{offset_in, Error, ParseError},
+    repr::{
+        color::Theme, format_with, split_radix_prefix, BinaryFormatOptions, Markup, RadixFormat,
+        ReprComponentKind,
+    },
     Binary,
 };
-use alloc::{
-    format,
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::{string::String, vec::Vec};
 use core::{
     clone::Clone,
     convert::{AsRef, From},
     default::Default,
-    fmt::Debug,
+    fmt::{self, Debug, Write},
     iter::Iterator,
     marker::Copy,
     option::Option::Some,
@@ -111,7 +112,8 @@ use core::{
 pub struct StringFormatOptions {
     radix_format: RadixFormat,
     compact: bool,
-    colored: bool,
+    theme: Theme,
+    markup: Markup,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -119,80 +121,84 @@ pub struct StringFormatOptions {
 // ------------------------------------------------------------------------------------------------
 
 pub fn string_representation(value: &Binary<'_>, options: &StringFormatOptions) -> String {
-    let prefix = if options.colored {
-        let style = ReprComponentKind::Prefix.display_style(true);
-        format!("{style}{}{style:#}", options.radix_format.prefix_str(),)
-    } else {
-        options.radix_format.prefix_str().to_string()
-    };
-    let quote = if options.colored {
-        let style = ReprComponentKind::Delimiter.display_style(true);
-        format!("{style}\"{style:#}")
-    } else {
-        '"'.to_string()
-    };
-    let underscore = if options.colored {
-        let style = ReprComponentKind::Separator.display_style(true);
-        format!("{style}_{style:#}")
-    } else {
-        '_'.to_string()
-    };
-    let mapped = value.as_ref().iter().map(|b| {
-        // do not use variable width compact representation as compact depends
-        // on knowing the width of each radix byte.
-        if options.colored {
-            let style = ByteKind::ascii_char_display_style(b, true);
-            format!("{style}{}{style:#}", options.radix_format.format(b, false))
-        } else {
-            options.radix_format.format(b, false).to_string()
-        }
-    });
-    format!(
-        "{prefix}{quote}{}{quote}",
-        if options.compact {
-            mapped.collect::<String>()
-        } else {
-            mapped.collect::<Vec<_>>().join(&underscore)
-        }
+    format_with(
+        value.len() * (options.radix_format.max_width() + 1) + 4,
+        |buffer| write_string_representation(buffer, value, options),
     )
 }
 
-pub fn parse_string_representation(s: &str) -> Result<Binary<'_>, Error> {
-    if !s.starts_with('0') {
-        return Err(Error::MissingRadixPrefix);
-    }
-    let s = &s[1..];
-    if !s.starts_with(['b', 'd', 'o', 'x', 'X']) {
-        return Err(Error::InvalidRadixPrefix);
-    }
-    let radix_char = s.chars().next().unwrap();
-    let s = &s[1..];
-    if !(s.starts_with('"') && s.ends_with('"')) {
-        return Err(Error::InvalidStringQuotes);
+///
+/// Write the string representation of `value` to `w`, see [`string_representation`].
+///
+pub fn write_string_representation<W: Write + ?Sized>(
+    w: &mut W,
+    value: &Binary<'_>,
+    options: &StringFormatOptions,
+) -> fmt::Result {
+    let markup = options.markup.resolve();
+    let prefix = options
+        .theme
+        .display_style(ReprComponentKind::Prefix, markup);
+    let quote = options
+        .theme
+        .display_style(ReprComponentKind::Delimiter, markup);
+    let underscore = options
+        .theme
+        .display_style(ReprComponentKind::Separator, markup);
+    write!(
+        w,
+        "{prefix}{}{prefix:#}{quote}{}{quote:#}",
+        options.radix_format.prefix_str(),
+        markup.text('"')
+    )?;
+    for (i, b) in value.as_ref().iter().enumerate() {
+        if i > 0 && !options.compact {
+            underscore.write_start(w)?;
+            w.write_char('_')?;
+            underscore.write_end(w)?;
+        }
+        // do not use variable width compact representation as compact depends
+        // on knowing the width of each radix byte.
+        let style = options.theme.byte_display_style(b, markup);
+        style.write_start(w)?;
+        options.radix_format.write(w, b, false)?;
+        style.write_end(w)?;
     }
-    let s = &s[1..s.len() - 1];
-    if s.is_empty() {
+    write!(w, "{quote}{}{quote:#}", markup.text('"'))
+}
+
+pub fn parse_string_representation(s: &str) -> Result<Binary<'_>, ParseError> {
+    let (byte_format, rest) = split_radix_prefix(s)?;
+    let Some(rest) = rest.strip_prefix('"') else {
+        return Err(Error::InvalidStringQuotes.at(s, offset_in(s, rest), 1, Some("`\"`")));
+    };
+    let Some(rest) = rest.strip_suffix('"') else {
+        return Err(Error::InvalidStringQuotes.at(s, s.len(), 0, Some("`\"`")));
+    };
+    if rest.is_empty() {
         Ok(Binary::from(Vec::new()))
     } else {
-        let byte_format = RadixFormat::from(Some(radix_char))?;
-        let radix = byte_format.radix();
         let width = byte_format.max_width();
-        let values: Vec<u8> = if s.contains('_') {
+        let values: Vec<u8> = if rest.contains('_') {
             let mut values = Vec::new();
-            let bytes = s.split('_');
-            for byte in bytes {
-                values.push(u8::from_str_radix(byte, radix)?);
+            for byte in rest.split('_') {
+                values.push(byte_format.parse_byte(s, byte)?);
             }
             values
         } else {
-            let mut rest = s;
+            let mut rest = rest;
             let mut values = Vec::new();
             while !rest.is_empty() {
-                if width > rest.len() {
-                    Err(Error::InvalidRepresentation)?
+                if width > rest.len() || !rest.is_char_boundary(width) {
+                    return Err(Error::InvalidRepresentation.at(
+                        s,
+                        offset_in(s, rest),
+                        rest.len().min(width),
+                        Some(byte_format.expected_byte()),
+                    ));
                 }
                 let (value, next) = rest.split_at(width);
-                values.push(u8::from_str_radix(value, radix)?);
+                values.push(byte_format.parse_byte(s, value)?);
                 rest = next;
             }
             values
@@ -246,11 +252,38 @@ impl StringFormatOptions {
         self
     }
 
-    /// Use color to denote byte kind according the ASCII conventions denoted by the
-    /// enums `ByteStyle` and `ReprStyle`.
+    /// Use color to denote the kind of each byte, a [`ByteKind`](crate::repr::ByteKind), and
+    /// each part of the representation, styled by the [`Theme`] set with [`Self::with_theme`].
     #[cfg(feature = "repr-color")]
     pub fn use_color(mut self, colored: bool) -> Self {
-        self.colored = colored;
+        self.markup = Markup::from_color(colored);
+        self
+    }
+
+    /// Use color according to `policy`, which is resolved, for [`ColorPolicy::Auto`] by
+    /// checking the environment and stream, each time the representation is written.
+    #[cfg(feature = "repr-color")]
+    pub fn with_color_policy(mut self, policy: ColorPolicy) -> Self {
+        self.markup = Markup::from_policy(policy);
+        self
+    }
+
+    /// Sets the theme used to style the representation when color is used.
+    #[cfg(feature = "repr-color")]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    #[cfg(any(feature = "repr-html", feature = "repr-markdown"))]
+    pub(crate) fn with_markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    #[cfg(all(feature = "std", feature = "repr-color"))]
+    pub(crate) fn for_terminal(mut self, is_terminal: bool) -> Self {
+        self.markup = self.markup.resolve_for(is_terminal);
         self
     }
 }
@@ -0,0 +1,167 @@
+#![cfg(feature = "repr-ascii85")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::{Error, ParseError},
+    repr::ascii85::{
+        ascii85_representation, parse_ascii85_representation, parse_ascii85_representation_with,
+        try_ascii85_representation, write_ascii85_representation, Ascii85FormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+const Z85_VALUE: [u8; 8] = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+
+#[test]
+fn test_ascii85_representation_adobe() {
+    let binary = Binary::from("Man sure.");
+    let repr = ascii85_representation(&binary, &Ascii85FormatOptions::default());
+    assert_eq!(repr, "9jqo^F*2M7/c");
+    assert_eq!(parse_ascii85_representation(&repr), Ok(binary.clone()));
+
+    let options = Ascii85FormatOptions::adobe();
+    let repr = ascii85_representation(&binary, &options);
+    assert_eq!(repr, "<~9jqo^F*2M7/c~>");
+    assert_eq!(
+        parse_ascii85_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[test]
+fn test_ascii85_representation_shortcuts() {
+    let binary = Binary::from(b"\0\0\0\0    \0\0");
+    assert_eq!(
+        ascii85_representation(&binary, &Ascii85FormatOptions::default()),
+        "z+<VdL!!!"
+    );
+    let options = Ascii85FormatOptions::btoa();
+    let repr = ascii85_representation(&binary, &options);
+    assert_eq!(repr, "zy!!!");
+    assert_eq!(
+        parse_ascii85_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[test]
+fn test_ascii85_representation_z85() {
+    let binary = Binary::from(Z85_VALUE);
+    let options = Ascii85FormatOptions::z85();
+    let repr = ascii85_representation(&binary, &options);
+    assert_eq!(repr, "HelloWorld");
+    assert_eq!(
+        parse_ascii85_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[test]
+fn test_ascii85_round_trip() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in [
+        Ascii85FormatOptions::default(),
+        Ascii85FormatOptions::adobe(),
+        Ascii85FormatOptions::btoa(),
+    ] {
+        for length in 0..8 {
+            let binary = Binary::from(&binary[..binary.len() - length]);
+            let repr = ascii85_representation(&binary, &options);
+            assert_eq!(
+                parse_ascii85_representation_with(&repr, &options),
+                Ok(binary)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_ascii85_parse_whitespace() {
+    assert_eq!(
+        parse_ascii85_representation("  <~9jqo^\n  F*2M7/c~>\n"),
+        Ok(Binary::from("Man sure."))
+    );
+}
+
+#[test]
+fn test_ascii85_parse_errors() {
+    let options = Ascii85FormatOptions::adobe();
+    assert_eq!(
+        parse_ascii85_representation("9jqo{F*2M7/c")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_ascii85_representation("9jqzo").map_err(|error| (error.offset(), error.into_kind())),
+        Err((3, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_ascii85_representation("9jqo^F").map_err(|error| (error.offset(), error.into_kind())),
+        Err((5, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_ascii85_representation("s8W-\"").map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_ascii85_representation_with("9jqo^", &options)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((0, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_ascii85_representation_with("<~9jqo^", &options)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((7, Error::InvalidEncoding))
+    );
+}
+
+#[test]
+fn test_z85_length_errors() {
+    let options = Ascii85FormatOptions::z85();
+    assert_eq!(
+        try_ascii85_representation(&Binary::from(&Z85_VALUE[..7]), &options),
+        Err(Error::InvalidLength {
+            length: 7,
+            multiple_of: 4
+        })
+    );
+    assert_eq!(
+        try_ascii85_representation(&Binary::from(Z85_VALUE), &options),
+        Ok("HelloWorld".to_string())
+    );
+    assert_eq!(
+        parse_ascii85_representation_with("HelloWorl", &options).map_err(ParseError::into_kind),
+        Err(Error::InvalidLength {
+            length: 9,
+            multiple_of: 5
+        })
+    );
+    assert_eq!(
+        parse_ascii85_representation_with("Hello~orld", &options)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((5, Error::InvalidEncoding))
+    );
+}
+
+#[test]
+fn test_z85_partial_group_write_error() {
+    let options = Ascii85FormatOptions::z85();
+    let mut buffer = String::new();
+    assert!(write_ascii85_representation(
+        &mut buffer,
+        &Binary::from(&[0x01, 0x02, 0x03]),
+        &options
+    )
+    .is_err());
+    assert!(buffer.is_empty());
+    assert!(std::panic::catch_unwind(|| {
+        ascii85_representation(&Binary::from(&Z85_VALUE[..7]), &options)
+    })
+    .is_err());
+}
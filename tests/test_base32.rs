@@ -0,0 +1,101 @@
+#![cfg(feature = "repr-base32")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::Error,
+    repr::base32::{
+        base32_representation, parse_base32_representation, parse_base32_representation_with,
+        Base32FormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+// Test vectors from RFC 4648, section 10.
+const RFC4648_VECTORS: [(&str, &str, &str); 7] = [
+    ("", "", ""),
+    ("f", "MY======", "CO======"),
+    ("fo", "MZXQ====", "CPNG===="),
+    ("foo", "MZXW6===", "CPNMU==="),
+    ("foob", "MZXW6YQ=", "CPNMUOG="),
+    ("fooba", "MZXW6YTB", "CPNMUOJ1"),
+    ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======"),
+];
+
+#[test]
+fn test_base32_representation_rfc4648() {
+    for (text, expected, _) in RFC4648_VECTORS {
+        let repr = base32_representation(&Binary::from(text), &Base32FormatOptions::default());
+        assert_eq!(repr, expected);
+        assert_eq!(parse_base32_representation(&repr), Ok(Binary::from(text)));
+    }
+}
+
+#[test]
+fn test_base32_representation_extended_hex() {
+    let options = Base32FormatOptions::default().with_extended_hex_alphabet();
+    for (text, _, expected) in RFC4648_VECTORS {
+        let repr = base32_representation(&Binary::from(text), &options);
+        assert_eq!(repr, expected);
+        assert_eq!(
+            parse_base32_representation_with(&repr, &options),
+            Ok(Binary::from(text))
+        );
+    }
+}
+
+#[test]
+fn test_base32_representation_crockford() {
+    let options = Base32FormatOptions::default()
+        .with_crockford_alphabet()
+        .compact(true);
+    let repr = base32_representation(&Binary::from("foobar"), &options);
+    assert_eq!(repr, "CSQPYRK1E8");
+    assert_eq!(
+        parse_base32_representation_with("csqp-yrk1-e8", &options),
+        Ok(Binary::from("foobar"))
+    );
+    assert_eq!(
+        parse_base32_representation_with("0123", &options),
+        parse_base32_representation_with("OIL3", &options),
+    );
+}
+
+#[test]
+fn test_base32_representation_compact_lower_case() {
+    let options = Base32FormatOptions::default()
+        .compact(true)
+        .lower_case(true);
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let repr = base32_representation(&binary, &options);
+    assert!(!repr.contains('='));
+    assert!(!repr.contains(char::is_uppercase));
+    assert_eq!(parse_base32_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_parse_base32_errors() {
+    assert_eq!(
+        parse_base32_representation("MZXW1===")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base32_representation("MZXW6Y").map_err(|error| (error.offset(), error.into_kind())),
+        Err((5, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base32_representation("MY=======")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((2, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base32_representation("MZ").map_err(|error| (error.offset(), error.into_kind())),
+        Err((1, Error::InvalidEncoding))
+    );
+}
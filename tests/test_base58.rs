@@ -0,0 +1,113 @@
+#![cfg(feature = "repr-base58")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::Error,
+    repr::base58::{
+        base58_representation, parse_base58_representation, parse_base58_representation_with,
+        Base58FormatOptions,
+    },
+    Binary,
+};
+
+#[cfg(feature = "repr-base58-check")]
+use wrapbin::error::ParseError;
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+#[test]
+fn test_base58_representation_bitcoin() {
+    let binary = Binary::from("Hello World!");
+    let repr = base58_representation(&binary, &Base58FormatOptions::default());
+    assert_eq!(repr, "2NEpo7TZRRrLZSi2U");
+    assert_eq!(parse_base58_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_base58_representation_flickr() {
+    let binary = Binary::from("Hello World!");
+    let options = Base58FormatOptions::default().with_flickr_alphabet();
+    let repr = base58_representation(&binary, &options);
+    assert_eq!(repr, "2nePN7syqqRkyrH2t");
+    assert_eq!(
+        parse_base58_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[test]
+fn test_base58_representation_leading_zeros() {
+    let binary = Binary::from([
+        0x00_u8, 0x00_u8, 0x00_u8, 0x28_u8, 0x7f_u8, 0xb4_u8, 0xcd_u8,
+    ]);
+    let repr = base58_representation(&binary, &Base58FormatOptions::default());
+    assert_eq!(repr, "111233QC4");
+    assert_eq!(parse_base58_representation(&repr), Ok(binary));
+
+    let binary = Binary::from([0x00_u8, 0x00_u8]);
+    let repr = base58_representation(&binary, &Base58FormatOptions::default());
+    assert_eq!(repr, "11");
+    assert_eq!(parse_base58_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_base58_representation_empty() {
+    let binary = Binary::from(Vec::new());
+    let repr = base58_representation(&binary, &Base58FormatOptions::default());
+    assert_eq!(repr, "");
+    assert_eq!(parse_base58_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_base58_round_trip() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let repr = base58_representation(&binary, &Base58FormatOptions::default());
+    assert_eq!(parse_base58_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_parse_base58_error() {
+    assert_eq!(
+        parse_base58_representation("2NEpo7T0RRrLZSi2U")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((7, Error::InvalidEncoding))
+    );
+}
+
+#[cfg(feature = "repr-base58-check")]
+const ADDRESS_PAYLOAD: [u8; 21] = [
+    0x00, 0xf5, 0x4a, 0x58, 0x51, 0xe9, 0x37, 0x2b, 0x87, 0x81, 0x0a, 0x8e, 0x60, 0xcd, 0xd2, 0xe7,
+    0xcf, 0xd8, 0x0b, 0x6e, 0x31,
+];
+
+#[cfg(feature = "repr-base58-check")]
+#[test]
+fn test_base58_check_representation() {
+    let binary = Binary::from(ADDRESS_PAYLOAD);
+    let options = Base58FormatOptions::default().use_check(true);
+    let repr = base58_representation(&binary, &options);
+    assert_eq!(repr, "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs");
+    assert_eq!(
+        parse_base58_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[cfg(feature = "repr-base58-check")]
+#[test]
+fn test_parse_base58_check_mismatch() {
+    let options = Base58FormatOptions::default().use_check(true);
+    assert_eq!(
+        parse_base58_representation_with("1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt", &options)
+            .map_err(ParseError::into_kind),
+        Err(Error::ChecksumMismatch)
+    );
+    assert_eq!(
+        parse_base58_representation_with("2NE", &options).map_err(ParseError::into_kind),
+        Err(Error::ChecksumMismatch)
+    );
+}
@@ -2,7 +2,11 @@
 
 use pretty_assertions::assert_eq;
 use wrapbin::{
-    repr::base64::{base64_representation, parse_base64_representation, Base64FormatOptions},
+    error::Error,
+    repr::base64::{
+        base64_representation, parse_base64_representation, parse_base64_representation_with,
+        Base64FormatOptions, Base64Padding,
+    },
     Binary,
 };
 
@@ -40,3 +44,118 @@ fn test_base64_representation_compact() {
     );
     assert_eq!(repr, &LOREM_IPSUM[..LOREM_IPSUM.len() - 2]);
 }
+
+#[test]
+fn test_parse_base64_compact() {
+    let parsed = parse_base64_representation(&LOREM_IPSUM[..LOREM_IPSUM.len() - 2]);
+    assert_eq!(parsed.unwrap().as_ref(), LOREM_IPSUM_TEXT.as_bytes());
+}
+
+#[test]
+fn test_base64_representation_url_safe() {
+    let binary = Binary::from([0xfb_u8, 0xff_u8, 0xbf_u8, 0x01_u8]);
+    let options = Base64FormatOptions::default().with_url_safe_alphabet();
+    let repr = base64_representation(&binary, &options);
+    assert_eq!(repr, "-_-_AQ==");
+    assert_eq!(
+        parse_base64_representation_with(&repr, &options),
+        Ok(binary)
+    );
+    assert_eq!(
+        parse_base64_representation("-_-_AQ==")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((0, Error::InvalidEncoding))
+    );
+}
+
+#[test]
+fn test_base64_padding_policy() {
+    let binary = Binary::from([0x01_u8, 0x0e_u8, 0xb2_u8, 0x8c_u8]);
+    let required = Base64FormatOptions::default().with_padding(Base64Padding::Required);
+    let optional = Base64FormatOptions::default().with_padding(Base64Padding::Optional);
+    let none = Base64FormatOptions::default().with_padding(Base64Padding::None);
+
+    assert_eq!(base64_representation(&binary, &required), "AQ6yjA==");
+    assert_eq!(base64_representation(&binary, &optional), "AQ6yjA==");
+    assert_eq!(base64_representation(&binary, &none), "AQ6yjA");
+
+    assert_eq!(
+        parse_base64_representation_with("AQ6yjA==", &required),
+        Ok(binary.clone())
+    );
+    assert_eq!(
+        parse_base64_representation_with("AQ6yjA", &required)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((6, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base64_representation_with("AQ6yjA==", &optional),
+        Ok(binary.clone())
+    );
+    assert_eq!(
+        parse_base64_representation_with("AQ6yjA", &optional),
+        Ok(binary.clone())
+    );
+    assert_eq!(
+        parse_base64_representation_with("AQ6yjA", &none),
+        Ok(binary.clone())
+    );
+    assert_eq!(
+        parse_base64_representation_with("AQ6yjA==", &none)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((8, Error::InvalidEncoding))
+    );
+}
+
+#[test]
+fn test_base64_line_wrap_pem() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let options = Base64FormatOptions::pem();
+    let repr = base64_representation(&binary, &options);
+    let lines: Vec<&str> = repr.split('\n').collect();
+    assert_eq!(lines.len(), 10);
+    assert!(lines[..9].iter().all(|line| line.len() == 64));
+    assert_eq!(lines.concat(), LOREM_IPSUM);
+    assert_eq!(
+        parse_base64_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[test]
+fn test_base64_line_wrap_mime() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let options = Base64FormatOptions::mime();
+    let repr = base64_representation(&binary, &options);
+    let lines: Vec<&str> = repr.split("\r\n").collect();
+    assert_eq!(lines.len(), 8);
+    assert!(lines[..7].iter().all(|line| line.len() == 76));
+    assert_eq!(lines.concat(), LOREM_IPSUM);
+    assert_eq!(
+        parse_base64_representation_with(&repr, &options),
+        Ok(binary)
+    );
+}
+
+#[test]
+fn test_parse_base64_error_offset() {
+    assert_eq!(
+        parse_base64_representation("AQ6y*A==")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base64_representation("AQ6yj").map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base64_representation_with("AQ6y\nj*==", &Base64FormatOptions::pem())
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((6, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_base64_representation("AQ6y\njA==")
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+}
@@ -0,0 +1,123 @@
+#![cfg(all(feature = "repr-array", feature = "repr-dump", feature = "repr-color"))]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::{
+        array::{array_representation, ArrayFormatOptions},
+        dump::{dump_representation, parse_dump_representation, DumpFormatOptions},
+        ColorPolicy, ColorStream,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+#[test]
+fn test_color_policy_always_never() {
+    let binary = Binary::from(b"\x00AB\xff");
+    let colored = dump_representation(
+        &binary,
+        &DumpFormatOptions::default().with_color_policy(ColorPolicy::Always),
+    );
+    assert!(colored.contains('\u{1b}'));
+    assert_eq!(parse_dump_representation(&colored), Ok(binary.clone()));
+
+    let plain = dump_representation(
+        &binary,
+        &DumpFormatOptions::default().with_color_policy(ColorPolicy::Never),
+    );
+    assert!(!plain.contains('\u{1b}'));
+    assert_eq!(
+        array_representation(
+            &binary,
+            &ArrayFormatOptions::default().with_color_policy(ColorPolicy::Never)
+        ),
+        "0X[00, 41, 42, FF]"
+    );
+    assert_eq!(ColorPolicy::from(true), ColorPolicy::Always);
+    assert_eq!(ColorPolicy::from(false), ColorPolicy::Never);
+}
+
+#[test]
+fn test_color_policy_auto_terminal() {
+    let auto = ColorPolicy::default();
+    assert_eq!(auto, ColorPolicy::Auto(ColorStream::Stdout));
+    assert!(auto.use_color_with(env(&[]), true));
+    assert!(!auto.use_color_with(env(&[]), false));
+    assert!(!ColorPolicy::Never.use_color_with(env(&[("CLICOLOR_FORCE", "1")]), true));
+    assert!(ColorPolicy::Always.use_color_with(env(&[("NO_COLOR", "1")]), false));
+}
+
+#[test]
+fn test_color_policy_auto_environment() {
+    let auto = ColorPolicy::Auto(ColorStream::Stderr);
+    assert!(!auto.use_color_with(env(&[("NO_COLOR", "1")]), true));
+    assert!(auto.use_color_with(env(&[("NO_COLOR", "")]), true));
+    assert!(!auto.use_color_with(env(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]), true));
+    assert!(auto.use_color_with(env(&[("CLICOLOR_FORCE", "1")]), false));
+    assert!(!auto.use_color_with(env(&[("CLICOLOR_FORCE", "0")]), false));
+    assert!(!auto.use_color_with(env(&[("CLICOLOR", "0")]), true));
+    assert!(auto.use_color_with(env(&[("CLICOLOR", "1")]), true));
+    assert!(auto.use_color_with(env(&[("CLICOLOR", "0"), ("CLICOLOR_FORCE", "1")]), false));
+}
+
+#[test]
+fn test_color_policy_default_is_unresolved() {
+    // The policy is kept as given and resolved when writing, so options compare equal wherever
+    // they are built.
+    assert_eq!(
+        DumpFormatOptions::default(),
+        DumpFormatOptions::default().with_color_policy(ColorPolicy::default())
+    );
+    assert_ne!(
+        DumpFormatOptions::default(),
+        DumpFormatOptions::default().with_color_policy(ColorPolicy::Auto(ColorStream::Stderr))
+    );
+    assert_ne!(
+        DumpFormatOptions::default(),
+        DumpFormatOptions::default().with_color_policy(ColorPolicy::Never)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_color_policy_write_terminal() {
+    use std::fs::{self, File};
+    use wrapbin::repr::write_terminal;
+
+    let binary = Binary::from(b"\x00AB\xff");
+    let path = std::env::temp_dir().join(format!("wrapbin-color-{}.txt", std::process::id()));
+    for (policy, colored) in [
+        (ColorPolicy::Always, true),
+        (ColorPolicy::Never, false),
+        (
+            ColorPolicy::default(),
+            ColorPolicy::default().use_color_for(false),
+        ),
+    ] {
+        let mut file = File::create(&path).unwrap();
+        write_terminal(
+            &mut file,
+            &binary,
+            ArrayFormatOptions::default().with_color_policy(policy),
+        )
+        .unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            written.contains('\u{1b}'),
+            colored,
+            "{policy:?}: {written:?}"
+        );
+    }
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,116 @@
+#![cfg(all(
+    feature = "repr-array",
+    feature = "repr-ascii85",
+    feature = "repr-base32",
+    feature = "repr-base58",
+    feature = "repr-base64",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-intel-hex",
+    feature = "repr-source",
+    feature = "repr-srec",
+    feature = "repr-string"
+))]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::{
+        array::ArrayFormatOptions, ascii85::Ascii85FormatOptions, base32::Base32FormatOptions,
+        base58::Base58FormatOptions, base64::Base64FormatOptions, dump::DumpFormatOptions,
+        escaped::EscapedFormatOptions, format, intel_hex::IntelHexFormatOptions,
+        source::SourceFormatOptions, srec::SRecordFormatOptions, string::StringFormatOptions,
+        BinaryFormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+#[test]
+fn test_display_all_representations() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in [
+        BinaryFormatOptions::from(ArrayFormatOptions::default()),
+        Ascii85FormatOptions::default().into(),
+        Base32FormatOptions::default().into(),
+        Base58FormatOptions::default().into(),
+        Base64FormatOptions::pem().into(),
+        DumpFormatOptions::classic_hex_dump().into(),
+        EscapedFormatOptions::default().into(),
+        IntelHexFormatOptions::default().into(),
+        SourceFormatOptions::default().into(),
+        SRecordFormatOptions::default().into(),
+        StringFormatOptions::default().into(),
+    ] {
+        assert_eq!(
+            binary.display(options.clone()).to_string(),
+            format(&binary, options)
+        );
+    }
+}
+
+#[test]
+fn test_display_width_alignment() {
+    let binary = Binary::from(b"ok");
+    let options = StringFormatOptions::default().with_lower_hex_bytes();
+    assert_eq!(
+        format!("{:12}|", binary.display(options)),
+        r#"0x"6f_6b"   |"#
+    );
+    assert_eq!(
+        format!("{:<12}|", binary.display(options)),
+        r#"0x"6f_6b"   |"#
+    );
+    assert_eq!(
+        format!("{:>12}|", binary.display(options)),
+        r#"   0x"6f_6b"|"#
+    );
+    assert_eq!(
+        format!("{:^12}|", binary.display(options)),
+        r#" 0x"6f_6b"  |"#
+    );
+    assert_eq!(
+        format!("{:-^13}|", binary.display(options)),
+        r#"--0x"6f_6b"--|"#
+    );
+    assert_eq!(format!("{:4}|", binary.display(options)), r#"0x"6f_6b"|"#);
+    assert_eq!(format!("{:9}|", binary.display(options)), r#"0x"6f_6b"|"#);
+}
+
+#[test]
+fn test_display_width_multi_line() {
+    let binary = Binary::from(b"ok");
+    let options = DumpFormatOptions::classic_hex_dump();
+    assert_eq!(
+        format!("{:>200}", binary.display(options.clone())),
+        format(&binary, options)
+    );
+}
+
+#[test]
+fn test_display_in_format_arguments() {
+    let binary = Binary::from(b"ok");
+    assert_eq!(
+        format!(
+            "read {} bytes: {}",
+            binary.len(),
+            binary.display(Base64FormatOptions::default())
+        ),
+        "read 2 bytes: b2s="
+    );
+}
+
+#[cfg(feature = "repr-color")]
+#[test]
+fn test_display_width_ignores_color() {
+    let binary = Binary::from(b"ok");
+    let options = ArrayFormatOptions::default()
+        .with_lower_hex_bytes()
+        .use_color(true);
+    let displayed = format!("{:>14}", binary.display(options));
+    assert_eq!(displayed, format!("    {}", format(&binary, options)));
+}
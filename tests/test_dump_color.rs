@@ -1,7 +1,13 @@
 #![cfg(all(feature = "repr-dump", feature = "repr-color"))]
 
 use wrapbin::{
-    repr::dump::{dump_representation, DumpFormatOptions},
+    repr::{
+        color::Style,
+        dump::{
+            diff_dump_representation, dump_representation, parse_dump_representation,
+            DumpAnnotation, DumpDiffOptions, DumpFormatOptions,
+        },
+    },
     Binary,
 };
 
@@ -15,7 +21,9 @@ const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
 fn test_colored_dump_representation_binary() {
     let repr = dump_representation(
         &Binary::from(LOREM_IPSUM_TEXT.as_bytes()),
-        &DumpFormatOptions::default().with_binary_bytes(),
+        &DumpFormatOptions::default()
+            .use_color(true)
+            .with_binary_bytes(),
     );
     println!("{repr}");
 }
@@ -24,7 +32,9 @@ fn test_colored_dump_representation_binary() {
 fn test_colored_dump_representation_octal() {
     let repr = dump_representation(
         &Binary::from(LOREM_IPSUM_TEXT.as_bytes()),
-        &DumpFormatOptions::default().with_octal_bytes(),
+        &DumpFormatOptions::default()
+            .use_color(true)
+            .with_octal_bytes(),
     );
     println!("{repr}");
 }
@@ -33,7 +43,9 @@ fn test_colored_dump_representation_octal() {
 fn test_colored_dump_representation_decimal() {
     let repr = dump_representation(
         &Binary::from(LOREM_IPSUM_TEXT.as_bytes()),
-        &DumpFormatOptions::default().with_decimal_bytes(),
+        &DumpFormatOptions::default()
+            .use_color(true)
+            .with_decimal_bytes(),
     );
     println!("{repr}");
 }
@@ -42,7 +54,9 @@ fn test_colored_dump_representation_decimal() {
 fn test_colored_dump_representation_upper_hex() {
     let repr = dump_representation(
         &Binary::from(LOREM_IPSUM_TEXT.as_bytes()),
-        &DumpFormatOptions::default().with_upper_hex_bytes(),
+        &DumpFormatOptions::default()
+            .use_color(true)
+            .with_upper_hex_bytes(),
     );
     println!("{repr}");
 }
@@ -51,7 +65,9 @@ fn test_colored_dump_representation_upper_hex() {
 fn test_colored_dump_representation_lower_hex() {
     let repr = dump_representation(
         &Binary::from(LOREM_IPSUM_TEXT.as_bytes()),
-        &DumpFormatOptions::default().with_lower_hex_bytes(),
+        &DumpFormatOptions::default()
+            .use_color(true)
+            .with_lower_hex_bytes(),
     );
     println!("{repr}");
 }
@@ -61,6 +77,7 @@ fn test_colored_dump_representation_ascii() {
     let repr = dump_representation(
         &Binary::from(LOREM_IPSUM_TEXT.as_bytes()),
         &DumpFormatOptions::default()
+            .use_color(true)
             .with_upper_hex_bytes()
             .show_ascii(true),
     );
@@ -73,8 +90,59 @@ fn test_colored_dump_ascii_extended_chart() {
     let repr = dump_representation(
         &chart,
         &DumpFormatOptions::default()
+            .use_color(true)
             .with_upper_hex_bytes()
             .show_extended_ascii(true),
     );
     println!("{repr}");
 }
+
+#[test]
+fn test_colored_dump_annotations() {
+    let binary = Binary::from(b"\x01\x00\x00\x2a\x00\x0bHello World!\xff");
+    let repr = dump_representation(
+        &binary,
+        &DumpFormatOptions::default()
+            .use_color(true)
+            .show_ascii_gutter(true)
+            .annotate(0..4, "header")
+            .annotate(4..6, "length")
+            .annotate(6..18, "payload")
+            .with_annotation(
+                DumpAnnotation::new(18..19, "checksum").with_style(Style::new().bold()),
+            ),
+    );
+    println!("{repr}");
+    assert_eq!(parse_dump_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_colored_diff_dump() {
+    let left = Binary::from(b"same old data here!");
+    let right = Binary::from(b"same new data here!");
+    let repr = diff_dump_representation(
+        &left,
+        &right,
+        &DumpDiffOptions::from(
+            DumpFormatOptions::default()
+                .use_color(true)
+                .with_bytes_per_line(8),
+        )
+        .only_differing_lines(0),
+    );
+    let highlighted = |value: &str| format!("\u{1b}[1m\u{1b}[97m\u{1b}[41m{value}\u{1b}[0m");
+    let lines = repr.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 5);
+    let line = lines[2];
+    assert!(line.starts_with("\u{1b}[2m000000:  \u{1b}[0m\u{1b}[1m\u{1b}[32m73\u{1b}[0m "));
+    let (left_values, right_values) = line.split_once(&highlighted("≠")).unwrap();
+    for value in ["6F", "6C", "64"] {
+        assert!(left_values.contains(&highlighted(value)));
+    }
+    for value in ["6E", "65", "77"] {
+        assert!(right_values.contains(&highlighted(value)));
+    }
+    assert!(!left_values.contains(&highlighted("73")));
+    assert_eq!(lines[3], "\u{1b}[2m*\u{1b}[0m");
+    assert_eq!(lines[4], "3 differing bytes, the first at offset 5.");
+}
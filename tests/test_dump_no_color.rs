@@ -2,7 +2,10 @@
 
 use pretty_assertions::assert_eq;
 use wrapbin::{
-    repr::dump::{dump_representation, DumpFormatOptions},
+    repr::dump::{
+        diff_dump_representation, diff_summary, dump_representation, parse_dump_representation,
+        DumpColumnWidth, DumpDiffOptions, DumpFormatOptions, DumpWordSize,
+    },
     Binary,
 };
 
@@ -207,6 +210,39 @@ fn test_dump_representation_lower_hex() {
     assert_eq!(EXPECTED, repr);
 }
 
+#[test]
+fn test_dump_presets_byte_radix() {
+    // `show_ascii(false)` in each preset leaves the preset's byte radix alone.
+    let binary = Binary::from(b"Hi!\n");
+    for (options, expected) in [
+        (
+            DumpFormatOptions::classic_hex_dump(),
+            "000000:  48 69 21 0A ",
+        ),
+        (
+            DumpFormatOptions::canonical_hex_dump(),
+            "000000:  48 69 21 0a                                        |Hi!.|",
+        ),
+        (DumpFormatOptions::hex_dump(), "000000:  48 69 21 0A "),
+        (DumpFormatOptions::lower_hex_dump(), "000000:  48 69 21 0a "),
+        (
+            DumpFormatOptions::octal_dump(),
+            "00000000:  110 151 041 012 ",
+        ),
+        (
+            DumpFormatOptions::decimal_dump(),
+            "00000000:  072 105 033 010 ",
+        ),
+        (
+            DumpFormatOptions::binary_dump(),
+            "000000:  01001000 01101001 00100001 00001010 ",
+        ),
+    ] {
+        let repr = dump_representation(&binary, &options);
+        assert_eq!(repr.lines().last(), Some(expected));
+    }
+}
+
 #[test]
 fn test_dump_representation_ascii() {
     const EXPECTED: &str = r##"0X       00 01 02 03 04 05 06 07 │ 08 09 0A 0B 0C 0D 0E 0F 
@@ -278,3 +314,332 @@ fn test_dump_ascii_extended_chart() {
     );
     assert_eq!(EXPECTED, repr);
 }
+
+#[test]
+fn test_dump_representation_ascii_gutter() {
+    const EXPECTED: &str = r##"000000:  48 65 6c 6c 6f 2c 20 57   6f 72 6c 64 21 0a 00 7c  |Hello, World!..||
+000010:  ff 41 42                                           |ÿAB|"##;
+    let repr = dump_representation(
+        &Binary::from(b"Hello, World!\n\x00|\xffAB"),
+        &DumpFormatOptions::canonical_hex_dump(),
+    );
+    assert_eq!(EXPECTED, repr);
+}
+
+#[test]
+fn test_dump_representation_code_pages() {
+    let options = DumpFormatOptions::canonical_hex_dump().with_bytes_per_line(8);
+    for (code_page_options, bytes, expected) in [
+        (
+            options.clone().with_cp037_code_page(),
+            &[0xc8_u8, 0x85, 0x93, 0x93, 0x96, 0x40, 0x5a, 0x25][..],
+            "000000:  c8 85 93 93 96 40 5a 25  |Hello !.|\n",
+        ),
+        (
+            options.clone().with_cp437_code_page(),
+            &[0x01, 0xc9, 0xcd, 0xbb, 0x20, 0x41, 0xe1, 0xff][..],
+            "000000:  01 c9 cd bb 20 41 e1 ff  |☺╔═╗ Aß.|\n",
+        ),
+        (
+            options.clone().with_utf8_code_page(),
+            "€1 ✓".as_bytes(),
+            "000000:  e2 82 ac 31 20 e2 9c 93  |€  1 ✓  |\n",
+        ),
+        (
+            options
+                .clone()
+                .with_char_mapping(|byte| byte.is_ascii_digit().then_some('#'))
+                .with_replacement_char('_'),
+            b"a1b2".as_slice(),
+            "000000:  61 31 62 32              |_#_#|",
+        ),
+    ] {
+        assert_eq!(
+            dump_representation(&Binary::from(bytes), &code_page_options),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_dump_representation_utf8_chars() {
+    const EXPECTED: &str = r##"000000:  G  r  ö     ß     e  20  |Grö ß e |
+000008:  €        1               |€  1|"##;
+    let repr = dump_representation(
+        &Binary::from("Größe €1".as_bytes()),
+        &DumpFormatOptions::canonical_hex_dump()
+            .with_bytes_per_line(8)
+            .with_utf8_code_page()
+            .show_ascii(true),
+    );
+    assert_eq!(EXPECTED, repr);
+}
+
+#[test]
+fn test_dump_representation_base_address_and_range() {
+    const EXPECTED: &str =
+        r##"000100:                    20 57   6f 72 6c 64 21           |       World!|"##;
+    let repr = dump_representation(
+        &Binary::from(b"Hello, World!\n"),
+        &DumpFormatOptions::canonical_hex_dump()
+            .with_base_address(0x100)
+            .with_offset(6)
+            .with_length(7),
+    );
+    assert_eq!(EXPECTED, repr);
+
+    const EXPECTED_LINES: &str = r##"0X       00 01 02 03 04 05 06 07 │ 08 09 0A 0B 0C 0D 0E 0F 
+         ────────────────────────│ ────────────────────────
+001FF0:                          │          2C 20 57 6F 72 
+002000:  6C 64 21 "##;
+    let repr = dump_representation(
+        &Binary::from(b"Hello, World!\n"),
+        &DumpFormatOptions::default()
+            .with_base_address(0x1FF6)
+            .with_range(5..13),
+    );
+    assert_eq!(EXPECTED_LINES, repr);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_dump_representation_base_address_wraps() {
+    let repr = dump_representation(
+        &Binary::from(b"Hello"),
+        &DumpFormatOptions::canonical_hex_dump().with_base_address(usize::MAX - 1),
+    );
+    assert_eq!(
+        repr.lines().collect::<Vec<_>>(),
+        [
+            "fffffffffffffff0:                                              48 65  |              He|",
+            "0000000000000000:  6c 6c 6f                                           |llo|",
+        ]
+    );
+
+    let repr = dump_representation(
+        &Binary::from(b"Hello"),
+        &DumpFormatOptions::canonical_hex_dump().with_base_address(0xFF_FFFE),
+    );
+    assert_eq!(
+        repr.lines().collect::<Vec<_>>(),
+        [
+            "0fffff0:                                              48 65  |              He|",
+            "1000000:  6c 6c 6f                                           |llo|",
+        ]
+    );
+}
+
+#[test]
+fn test_dump_representation_squeeze() {
+    const EXPECTED: &str = r##"000000:  00 00 00 00 00 00 00 00   00 00 00 00 00 00 00 00  |................|
+*
+000030:  41 41 41 41 41 41 41 41   41 41 41 41 41 41 41 41  |AAAAAAAAAAAAAAAA|
+*
+000060:  41 41 41 41 41 41 41 41   41 41 41 41 41 41 41 41  |AAAAAAAAAAAAAAAA|
+"##;
+    let mut bytes = vec![0_u8; 48];
+    bytes.extend([b'A'; 64]);
+    let repr = dump_representation(
+        &Binary::from(bytes),
+        &DumpFormatOptions::canonical_hex_dump().squeeze(true),
+    );
+    assert_eq!(EXPECTED, repr);
+}
+
+#[test]
+fn test_dump_representation_groups() {
+    const EXPECTED: &str = r##"0x       00 01 02 03 │ 04 05 06 07 │ 08 09 0a 0b │ 0c 0d 0e 0f 
+         ────────────│ ────────────│ ────────────│ ────────────
+000000:  4c 6f 72 65 │ 6d 20 69 70 │ 73 75 6d 20 │ 64 6f 6c 6f 
+000010:  72 20 73 69 │ 74 "##;
+    let repr = dump_representation(
+        &Binary::from(&LOREM_IPSUM_TEXT.as_bytes()[..21]),
+        &DumpFormatOptions::lower_hex_dump().with_groups_of(4, 4),
+    );
+    assert_eq!(EXPECTED, repr);
+}
+
+#[test]
+fn test_dump_representation_bytes_per_line() {
+    const EXPECTED: &str = r##"0X       00 01 02 03 04 05 06 07 │ 08 09 0A 0B 0C 0D 0E 0F │ 10 11 12 13 
+         ────────────────────────│ ────────────────────────│ ────────────
+000000:  4C 6F 72 65 6D 20 69 70 │ 73 75 6D 20 64 6F 6C 6F │ 72 20 73 69  |Lorem ipsum dolor si|
+000014:  74 20 61 6D 65 74 2C 20 │ 63 6F                                  |t amet, co|"##;
+    let repr = dump_representation(
+        &Binary::from(&LOREM_IPSUM_TEXT.as_bytes()[..30]),
+        &DumpFormatOptions::default()
+            .with_bytes_per_line(20)
+            .show_ascii_gutter(true),
+    );
+    assert_eq!(EXPECTED, repr);
+}
+
+#[test]
+fn test_dump_representation_wide_lines() {
+    // Bytes counting up from zero, so that the data matches the column indices.
+    let binary = Binary::from((0..=255_u8).cycle().take(300).collect::<Vec<u8>>());
+    let repr = dump_representation(
+        &binary,
+        &DumpFormatOptions::default().with_bytes_per_line(300),
+    );
+    let lines = repr.lines().collect::<Vec<_>>();
+    assert!(lines[0].contains(" FE FF │ 00 01 02 "));
+    assert_eq!(
+        lines[0].strip_prefix("0X       "),
+        lines[2].strip_prefix("000000:  ")
+    );
+    assert_eq!(parse_dump_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_dump_representation_annotations() {
+    const EXPECTED: &str = r##"0x       00 01 02 03 04 05 06 07 
+         ────────────────────────
+000000:  01 00 00 2a 00 0b 48 65  ◂ header, version, length, payload
+000008:  6c 6c 6f 20 57 6f 72 6c  ◂ payload
+000010:  64 21 ff                 ◂ payload, checksum"##;
+    let repr = dump_representation(
+        &Binary::from(b"\x01\x00\x00\x2a\x00\x0bHello World!\xff"),
+        &DumpFormatOptions::lower_hex_dump()
+            .one_column_of(DumpColumnWidth::Eight)
+            .annotate(0..4, "header")
+            .annotate(0..1, "version")
+            .annotate(4..6, "length")
+            .annotate(6..18, "payload")
+            .annotate(18..19, "checksum"),
+    );
+    assert_eq!(EXPECTED, repr);
+}
+
+#[test]
+fn test_diff_dump_representation() {
+    const EXPECTED: &str = r##"0X       00 01 02 03 04 05 06 07 │ 00 01 02 03 04 05 06 07 
+         ────────────────────────│ ────────────────────────
+000000:  48 65 6C 6C 6F 2C 20 57 │ 48 65 6C 6C 6F 2C 20 57 
+000008:  6F 72 6C 64 21 00 00 00 ≠ 6F 72 6C 64 3F 00 00 00 
+000010:  00 00 00 00 00 00 00 00 │ 00 00 00 00 00 00 00 00 
+000018:  00 00 00 00 00 00 00 00 │ 00 00 00 00 00 00 00 00 
+000020:  00 00 00 00 00 00 00 00 │ 00 00 00 00 00 00 00 00 
+000028:  01 02                   ≠ 01 02 03 04 
+3 differing bytes, the first at offset 12; lengths differ, 42 and 44 bytes."##;
+    let mut left = b"Hello, World!".to_vec();
+    left.resize(40, 0);
+    let mut right = left.clone();
+    right[12] = b'?';
+    left.extend([1, 2]);
+    right.extend([1, 2, 3, 4]);
+    let options =
+        DumpDiffOptions::from(DumpFormatOptions::default().one_column_of(DumpColumnWidth::Eight));
+    let repr = diff_dump_representation(
+        &Binary::from(left.clone()),
+        &Binary::from(right.clone()),
+        &options,
+    );
+    assert_eq!(EXPECTED, repr);
+
+    const EXPECTED_CONTEXT: &str = r##"000000:  48 65 6C 6C 6F 2C 20 57  |Hello, W| │ 48 65 6C 6C 6F 2C 20 57  |Hello, W|
+000008:  6F 72 6C 64 21 00 00 00  |orld!...| ≠ 6F 72 6C 64 3F 00 00 00  |orld?...|
+000010:  00 00 00 00 00 00 00 00  |........| │ 00 00 00 00 00 00 00 00  |........|
+*
+000020:  00 00 00 00 00 00 00 00  |........| │ 00 00 00 00 00 00 00 00  |........|
+000028:  01 02                    |..|       ≠ 01 02 03 04              |....|
+3 differing bytes, the first at offset 12; lengths differ, 42 and 44 bytes."##;
+    let options = options
+        .with_layout(
+            DumpFormatOptions::default()
+                .one_column_of(DumpColumnWidth::Eight)
+                .has_index_header_line(false)
+                .show_ascii_gutter(true),
+        )
+        .only_differing_lines(1);
+    let repr = diff_dump_representation(&Binary::from(left), &Binary::from(right), &options);
+    assert_eq!(EXPECTED_CONTEXT, repr);
+}
+
+#[test]
+fn test_diff_dump_representation_empty_side() {
+    const EXPECTED: &str = r##"0X       00 01 02 03 04 05 06 07 │ 00 01 02 03 04 05 06 07 
+         ────────────────────────│ ────────────────────────
+000000:                          ≠ 30 31 32 33 34 35 36 37 
+000008:                          ≠ 38 39 61 62 63 64 65 66 
+000010:                          ≠ 67 68 69 6A 6B 
+21 differing bytes, the first at offset 0; lengths differ, 0 and 21 bytes."##;
+    let options = DumpDiffOptions::from(DumpFormatOptions::default().with_bytes_per_line(8))
+        .only_differing_lines(1);
+    let empty = Binary::from(Vec::new());
+    let value = Binary::from(b"0123456789abcdefghijk");
+    assert_eq!(diff_dump_representation(&empty, &value, &options), EXPECTED);
+    assert_eq!(
+        diff_dump_representation(&empty, &empty, &options),
+        concat!(
+            "0X       00 01 02 03 04 05 06 07 │ 00 01 02 03 04 05 06 07 \n",
+            "         ────────────────────────│ ────────────────────────\n",
+            "No differences, 0 bytes."
+        )
+    );
+}
+
+#[test]
+fn test_diff_summary() {
+    let summary = diff_summary(&Binary::from("same"), &Binary::from("same"));
+    assert!(summary.is_identical());
+    assert_eq!(summary.to_string(), "No differences, 4 bytes.");
+    let summary = diff_summary(&Binary::from("same"), &Binary::from("sane"));
+    assert_eq!(summary.differing_bytes(), 1);
+    assert_eq!(summary.first_difference(), Some(2));
+    assert!(!summary.has_length_mismatch());
+    assert_eq!(
+        summary.to_string(),
+        "1 differing byte, the first at offset 2."
+    );
+}
+
+#[test]
+fn test_dump_representation_words() {
+    let binary = Binary::from([
+        0x01_u8, 0x02, 0x03, 0x04, 0xff, 0xff, 0xff, 0xfe, 0x00, 0x00, 0x80, 0x3f, 0x0a,
+    ]);
+    const EXPECTED_BIG: &str = r##"0x       00000000 00000004 │ 00000008 0000000c 
+         ──────────────────│ ──────────────────
+000000:  01020304 fffffffe │ 0000803f       0a "##;
+    let repr = dump_representation(
+        &binary,
+        &DumpFormatOptions::lower_hex_dump().with_word_size(DumpWordSize::Four),
+    );
+    assert_eq!(EXPECTED_BIG, repr);
+
+    const EXPECTED_LITTLE: &str =
+        r##"000000:  04030201 feffffff   3f800000       0a  |....ÿÿÿþ...?.|"##;
+    let repr = dump_representation(
+        &binary,
+        &DumpFormatOptions::canonical_hex_dump()
+            .with_word_size(DumpWordSize::Four)
+            .with_little_endian_words(),
+    );
+    assert_eq!(EXPECTED_LITTLE, repr);
+
+    const EXPECTED_SIGNED: &str = r##"0d         000000 000002 000004 000006 │ 000008 000010 000012 000014 
+           ────────────────────────────│ ────────────────────────────
+00000000:     513   1027     -1   -257 │      0  16256     10 "##;
+    let repr = dump_representation(
+        &binary,
+        &DumpFormatOptions::decimal_dump()
+            .with_word_size(DumpWordSize::Two)
+            .with_little_endian_words()
+            .with_signed_words()
+            .with_groups_of(2, 8),
+    );
+    assert_eq!(EXPECTED_SIGNED, repr);
+
+    const EXPECTED_FLOAT: &str = "000000:   1.5399896e-36  -1.7014117e38    1.0000000e0 \n";
+    let repr = dump_representation(
+        &Binary::from(&binary[..12]),
+        &DumpFormatOptions::default()
+            .has_index_header_line(false)
+            .with_word_size(DumpWordSize::Four)
+            .with_little_endian_words()
+            .with_float_words()
+            .with_groups_of(1, 12),
+    );
+    assert_eq!(EXPECTED_FLOAT, repr);
+}
@@ -0,0 +1,208 @@
+#![cfg(feature = "repr-dump")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::{Error, ParseError},
+    repr::dump::{dump_representation, parse_dump_representation, DumpFormatOptions},
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+fn assert_round_trip(binary: &Binary<'_>, options: &DumpFormatOptions) {
+    let repr = dump_representation(binary, options);
+    let parsed = parse_dump_representation(&repr);
+    assert_eq!(parsed.as_ref(), Ok(binary), "failed to parse:\n{repr}");
+}
+
+fn all_presets() -> Vec<DumpFormatOptions> {
+    vec![
+        DumpFormatOptions::default(),
+        DumpFormatOptions::classic_hex_dump(),
+        DumpFormatOptions::hex_dump(),
+        DumpFormatOptions::octal_dump(),
+        DumpFormatOptions::binary_dump(),
+        DumpFormatOptions::decimal_dump(),
+        DumpFormatOptions::lower_hex_dump(),
+        DumpFormatOptions::ascii_hex_dump(),
+        DumpFormatOptions::default().show_ascii(true),
+        DumpFormatOptions::canonical_hex_dump(),
+        DumpFormatOptions::ascii_hex_dump().show_ascii_gutter(true),
+    ]
+}
+
+#[test]
+fn test_parse_dump_presets_text() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in all_presets() {
+        assert_round_trip(&binary, &options);
+    }
+}
+
+#[test]
+fn test_parse_dump_presets_all_bytes() {
+    let binary = Binary::from((0u8..=255).collect::<Vec<u8>>());
+    for options in all_presets() {
+        assert_round_trip(&binary, &options);
+    }
+}
+
+#[test]
+fn test_parse_dump_presets_short_lines() {
+    for length in [0, 1, 7, 8, 9, 15, 16, 17] {
+        let binary = Binary::from((0u8..length).collect::<Vec<u8>>());
+        for options in all_presets() {
+            assert_round_trip(&binary, &options);
+        }
+    }
+}
+
+#[test]
+fn test_parse_dump_without_header() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    assert_round_trip(
+        &binary,
+        &DumpFormatOptions::classic_hex_dump().has_index_header_line(false),
+    );
+}
+
+#[test]
+fn test_parse_dump_ascii_separator_char() {
+    let binary = Binary::from(b"0123456789ABCDEF-------- - - - -");
+    assert_round_trip(&binary, &DumpFormatOptions::ascii_hex_dump());
+}
+
+#[test]
+fn test_parse_dump_groups() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in [
+        DumpFormatOptions::default().with_groups_of(4, 4),
+        DumpFormatOptions::default().with_groups_of(1, 4),
+        DumpFormatOptions::default().with_bytes_per_line(20),
+        DumpFormatOptions::lower_hex_dump().with_groups_of(3, 8),
+        DumpFormatOptions::canonical_hex_dump().with_groups_of(5, 4),
+    ] {
+        assert_round_trip(&binary, &options);
+    }
+}
+
+#[test]
+fn test_parse_dump_annotated() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in all_presets() {
+        assert_round_trip(
+            &binary,
+            &options
+                .annotate(0..5, "lorem")
+                .annotate(6..11, "ipsum")
+                .annotate(0..usize::MAX, "text"),
+        );
+    }
+}
+
+#[test]
+fn test_parse_dump_squeezed() {
+    let mut bytes = vec![0_u8; 100];
+    bytes.extend(LOREM_IPSUM_TEXT.bytes());
+    bytes.extend([0xff_u8; 250]);
+    let binary = Binary::from(bytes);
+    for options in all_presets() {
+        assert_round_trip(&binary, &options.squeeze(true));
+    }
+}
+
+#[test]
+fn test_parse_dump_wide_line_index() {
+    let mut bytes = vec![0_u8; 0x100_0000];
+    bytes.extend(LOREM_IPSUM_TEXT.bytes().take(40));
+    let binary = Binary::from(bytes);
+    for options in [
+        DumpFormatOptions::default(),
+        DumpFormatOptions::lower_hex_dump(),
+        DumpFormatOptions::canonical_hex_dump(),
+    ] {
+        assert_round_trip(&binary, &options.squeeze(true));
+    }
+}
+
+#[test]
+fn test_parse_dump_error_squeeze_marker() {
+    let parsed = parse_dump_representation(
+        r##"000000:  00 00 00 00 00 00 00 00 │ 00 00 00 00 00 00 00 00
+*
+000018:  00 00 00 00 00 00 00 00 │ 00 00 00 00 00 00 00 00"##,
+    )
+    .map_err(ParseError::into_kind);
+    assert_eq!(
+        parsed,
+        Err(Error::InvalidLineOffset {
+            expected: 16,
+            found: "000018".to_string()
+        })
+    );
+    let parsed = parse_dump_representation(
+        r##"000000:  00 00 00 00 00 00 00 00 │ 00 00 00 00 00 00 00 00
+*"##,
+    )
+    .map_err(ParseError::into_kind);
+    assert_eq!(parsed, Err(Error::InvalidRepresentation));
+}
+
+#[test]
+fn test_parse_dump_classic() {
+    let parsed = parse_dump_representation(
+        r##"0X       00 01 02 03 04 05 06 07 - 08 09 0A 0B 0C 0D 0E 0F
+000000:  7B E6 D4 F2 25 5C 62 D3 - 21 24 AB 7E 40 F1 7B CE
+000010:  17 3C 08 D2 D1 CE CC 17 - "##,
+    );
+    assert_eq!(
+        parsed,
+        Ok(Binary::from([
+            0x7b_u8, 0xe6_u8, 0xd4_u8, 0xf2_u8, 0x25_u8, 0x5c_u8, 0x62_u8, 0xd3_u8, 0x21_u8,
+            0x24_u8, 0xab_u8, 0x7e_u8, 0x40_u8, 0xf1_u8, 0x7b_u8, 0xce_u8, 0x17_u8, 0x3c_u8,
+            0x08_u8, 0xd2_u8, 0xd1_u8, 0xce_u8, 0xcc_u8, 0x17_u8,
+        ]))
+    );
+}
+
+#[test]
+fn test_parse_dump_error_line_offset() {
+    let parsed = parse_dump_representation(
+        r##"0X       00 01 02 03 04 05 06 07 - 08 09 0A 0B 0C 0D 0E 0F
+000000:  7B E6 D4 F2 25 5C 62 D3 - 21 24 AB 7E 40 F1 7B CE
+000020:  17 3C 08 D2 D1 CE CC 17 - "##,
+    )
+    .map_err(ParseError::into_kind);
+    assert_eq!(
+        parsed,
+        Err(Error::InvalidLineOffset {
+            expected: 16,
+            found: "000020".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_parse_dump_error_short_line() {
+    let parsed = parse_dump_representation(
+        r##"0X       00 01 02 03 04 05 06 07 - 08 09 0A 0B 0C 0D 0E 0F
+000000:  7B E6 D4 F2 25 5C 62 D3 - 21 24 AB 7E 40 F1 7B
+00000F:  17 3C 08 D2 D1 CE CC 17 - "##,
+    )
+    .map_err(ParseError::into_kind);
+    assert_eq!(parsed, Err(Error::InvalidRepresentation));
+}
+
+#[test]
+fn test_parse_dump_error_missing_index() {
+    let parsed = parse_dump_representation(
+        r##"0X       00 01 02 03 04 05 06 07 - 08 09 0A 0B 0C 0D 0E 0F
+7B E6 D4 F2 25 5C 62 D3 - 21 24 AB 7E 40 F1 7B CE"##,
+    )
+    .map_err(ParseError::into_kind);
+    assert_eq!(parsed, Err(Error::InvalidRepresentation));
+}
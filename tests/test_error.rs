@@ -0,0 +1,279 @@
+#![cfg(all(
+    feature = "repr-array",
+    feature = "repr-ascii85",
+    feature = "repr-base32",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-intel-hex",
+    feature = "repr-string"
+))]
+
+use pretty_assertions::assert_eq;
+use std::error::Error as _;
+use wrapbin::{
+    error::{Error, ParseError, Result},
+    repr::{
+        array::parse_array_representation, ascii85::parse_ascii85_representation,
+        base32::parse_base32_representation, escaped::parse_escaped_representation, parse,
+        string::parse_string_representation,
+    },
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+fn location(
+    result: core::result::Result<wrapbin::Binary<'_>, ParseError>,
+) -> (usize, usize, usize, String, Option<&'static str>) {
+    let error = result.unwrap_err();
+    (
+        error.offset(),
+        error.line(),
+        error.column(),
+        error.found().to_string(),
+        error.expected(),
+    )
+}
+
+#[test]
+fn test_result_alias() {
+    fn first(s: &str) -> Result<u8> {
+        Ok(parse(s)?.as_slice()[0])
+    }
+    assert_eq!(first("0x[2a]").ok(), Some(42));
+    assert!(first("0x[2a").is_err());
+}
+
+#[test]
+fn test_error_array_location() {
+    assert_eq!(
+        location(parse_array_representation("[01]")),
+        (
+            0,
+            1,
+            1,
+            "[".to_string(),
+            Some("a radix prefix: `0b`, `0d`, `0o`, `0x` or `0X`")
+        )
+    );
+    assert_eq!(
+        location(parse_array_representation("0c[01]")),
+        (
+            0,
+            1,
+            1,
+            "0c".to_string(),
+            Some("a radix prefix: `0b`, `0d`, `0o`, `0x` or `0X`")
+        )
+    );
+    assert_eq!(
+        location(parse_array_representation("0x01]")),
+        (2, 1, 3, "0".to_string(), Some("`[`"))
+    );
+    assert_eq!(
+        location(parse_array_representation("0x[01, 0e\n b2, 8c")),
+        (17, 2, 8, String::new(), Some("`]`"))
+    );
+    assert_eq!(
+        location(parse_array_representation("0o[001, 8, 003]")),
+        (8, 1, 9, "8".to_string(), Some("an octal byte value"))
+    );
+}
+
+#[test]
+fn test_error_string_location() {
+    assert_eq!(
+        location(parse_string_representation(r#"0x"01_0g""#)),
+        (6, 1, 7, "0g".to_string(), Some("a hexadecimal byte value"))
+    );
+    assert_eq!(
+        location(parse_string_representation(r#"0o"0010""#)),
+        (6, 1, 7, "0".to_string(), Some("an octal byte value"))
+    );
+    assert_eq!(
+        location(parse_string_representation(r#"0x"0102"#)),
+        (7, 1, 8, String::new(), Some("`\"`"))
+    );
+}
+
+#[test]
+fn test_error_escaped_location() {
+    assert_eq!(
+        location(parse_escaped_representation(r#"b"ab\qc""#)),
+        (4, 1, 5, r"\q".to_string(), Some("an escape sequence"))
+    );
+    assert_eq!(
+        location(parse_escaped_representation(r#"b"ab\x4""#)),
+        (4, 1, 5, r"\x4".to_string(), Some("an escape sequence"))
+    );
+    assert_eq!(
+        location(parse_escaped_representation(r#""ab" x"#)),
+        (5, 1, 6, "x".to_string(), Some("`\"`"))
+    );
+}
+
+#[test]
+fn test_error_encoded_location() {
+    assert_eq!(
+        location(parse_base32_representation("MZXW1===")),
+        (4, 1, 5, "1".to_string(), Some("a base32 symbol"))
+    );
+    assert_eq!(
+        location(parse_ascii85_representation("9jqo{F*2M7/c")),
+        (4, 1, 5, "{".to_string(), Some("an Ascii85 digit"))
+    );
+    assert_eq!(
+        location(parse_ascii85_representation("<~9jqo^")),
+        (7, 1, 8, String::new(), Some("`~>`"))
+    );
+}
+
+#[test]
+fn test_error_record_location() {
+    assert_eq!(
+        location(parse(":0300300002337A1E\n:0300300002337A1F\n:00000001FF\n")),
+        (
+            33,
+            2,
+            16,
+            "1F".to_string(),
+            Some("the checksum of the record")
+        )
+    );
+    assert_eq!(
+        location(parse(":0300300002337A1E\n:03003000G2337A1E\n:00000001FF\n")),
+        (
+            27,
+            2,
+            10,
+            "G2".to_string(),
+            Some("a hexadecimal byte value")
+        )
+    );
+    assert_eq!(
+        location(parse(":0300300002337A1E\n")),
+        (17, 1, 18, String::new(), Some("an end of file record"))
+    );
+}
+
+#[test]
+fn test_error_dump_location() {
+    let dump = "0X       00 01 02 03\n000000:  00 01 02 03\n000004:  00 01 0G 03\n";
+    assert_eq!(
+        location(parse(dump)),
+        (
+            57,
+            3,
+            16,
+            "0G".to_string(),
+            Some("a hexadecimal byte value")
+        )
+    );
+    let dump = "0X       00 01 02 03\n000000:  00 01 02 03\n000005:  00 01 02 03\n";
+    let error = parse(dump).unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &Error::InvalidLineOffset {
+            expected: 4,
+            found: "000005".to_string()
+        }
+    );
+    assert_eq!(
+        location(Err(error)),
+        (
+            42,
+            3,
+            1,
+            "000005".to_string(),
+            Some("the offset of the first byte in the line")
+        )
+    );
+}
+
+#[test]
+fn test_error_dump_location_styled() {
+    // Offsets within a line that had escape sequences stripped cannot be mapped back, so the
+    // whole line is located.
+    let line = "000004:  \u{1b}[1m00\u{1b}[0m 01 0G 03";
+    let dump = format!("0X       00 01 02 03\n000000:  00 01 02 03\n{line}\n");
+    assert_eq!(
+        location(parse(&dump)),
+        (42, 3, 1, line.to_string(), Some("a hexadecimal byte value"))
+    );
+}
+
+#[test]
+fn test_error_parse_leading_whitespace() {
+    assert_eq!(
+        location(parse("  \n 0x[01, zz]")),
+        (11, 2, 9, "zz".to_string(), Some("a hexadecimal byte value"))
+    );
+    assert_eq!(
+        location(parse("\n\n  hello, world")),
+        (
+            4,
+            3,
+            3,
+            "hello, world".to_string(),
+            Some("one of the enabled representations")
+        )
+    );
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        parse_array_representation("0x[01, 0e\n b2, 8c")
+            .unwrap_err()
+            .to_string(),
+        "The binary array representation must be correctly enclosed in brackets: '[' and ']'. \
+         Found the end of input at line 2, column 8; expected `]`."
+    );
+    assert_eq!(
+        parse_string_representation(r#"0x"01_0g""#)
+            .unwrap_err()
+            .to_string(),
+        "Failed to parse individual byte representation; source error: invalid digit found in \
+         string. Found '0g' at line 1, column 7; expected a hexadecimal byte value."
+    );
+}
+
+#[test]
+fn test_error_kind_and_source() {
+    let error = parse_array_representation("0x[zz]").unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        Error::InvalidByteRepresentation { .. }
+    ));
+    assert_eq!(
+        error.source().map(ToString::to_string),
+        Some("invalid digit found in string".to_string())
+    );
+    assert!(matches!(
+        error.into_kind(),
+        Error::InvalidByteRepresentation { .. }
+    ));
+
+    let error = parse_array_representation("0x[01").unwrap_err();
+    assert_eq!(Error::from(error), Error::InvalidArrayBrackets);
+}
+
+#[cfg(feature = "miette")]
+#[test]
+fn test_error_diagnostic() {
+    use miette::Diagnostic;
+
+    let error = parse_array_representation("0x[01, 0g]").unwrap_err();
+    assert_eq!(
+        error.code().map(|code| code.to_string()),
+        Some("wrapbin::invalid_byte_representation".to_string())
+    );
+    let labels = error.labels().unwrap().collect::<Vec<_>>();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].offset(), 7);
+    assert_eq!(labels[0].len(), 2);
+    assert_eq!(labels[0].label(), Some("expected a hexadecimal byte value"));
+
+    assert!(Error::ChecksumMismatch.labels().is_none());
+}
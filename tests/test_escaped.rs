@@ -0,0 +1,104 @@
+#![cfg(feature = "repr-escaped")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::{Error, ParseError},
+    repr::escaped::{escaped_representation, parse_escaped_representation, EscapedFormatOptions},
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+#[test]
+fn test_escaped_rust() {
+    let binary = Binary::from(b"say \"hi\"\t\\\x07\x00\x7f\xa0");
+    let repr = escaped_representation(&binary, &EscapedFormatOptions::default());
+    assert_eq!(repr, r#"b"say \"hi\"\t\\\x07\0\x7f\xa0""#);
+    assert_eq!(parse_escaped_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_escaped_c() {
+    let binary = Binary::from(b"\x01BAD\x00\x007\xffz");
+    let repr = escaped_representation(&binary, &EscapedFormatOptions::default().with_c_dialect());
+    assert_eq!(repr, r#""\x01\102AD\0\0007\xffz""#);
+    assert_eq!(parse_escaped_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_escaped_round_trip() {
+    let binary = Binary::from(
+        (0..=255_u8)
+            .chain(LOREM_IPSUM_TEXT.bytes())
+            .collect::<Vec<_>>(),
+    );
+    for options in [
+        EscapedFormatOptions::default().with_rust_dialect(),
+        EscapedFormatOptions::default().with_c_dialect(),
+    ] {
+        let repr = escaped_representation(&binary, &options);
+        assert_eq!(parse_escaped_representation(&repr), Ok(binary.clone()));
+    }
+}
+
+#[test]
+fn test_escaped_parse_c_escapes() {
+    assert_eq!(
+        parse_escaped_representation(r#""\a\b\f\v\?\'\1\12\123\x4\x041""#),
+        Ok(Binary::from(b"\x07\x08\x0c\x0b?'\x01\x0a\x53\x04\x41"))
+    );
+    assert_eq!(
+        parse_escaped_representation("\"abc\"  \"\\x41\"\n\"def\""),
+        Ok(Binary::from("abcAdef"))
+    );
+    assert_eq!(parse_escaped_representation("\"é\""), Ok(Binary::from("é")));
+}
+
+#[test]
+fn test_escaped_parse_errors() {
+    assert_eq!(
+        parse_escaped_representation("abc").map_err(ParseError::into_kind),
+        Err(Error::InvalidStringQuotes)
+    );
+    assert_eq!(
+        parse_escaped_representation(r#"b"abc"#).map_err(ParseError::into_kind),
+        Err(Error::InvalidStringQuotes)
+    );
+    assert_eq!(
+        parse_escaped_representation(r#"b"a" "b""#).map_err(ParseError::into_kind),
+        Err(Error::InvalidStringQuotes)
+    );
+    assert_eq!(
+        parse_escaped_representation(r#"b"ab\a""#)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((4, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_escaped_representation(r#"b"\x4""#)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((2, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_escaped_representation(r#"b"\101""#)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((2, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_escaped_representation("b\"é\"").map_err(|error| (error.offset(), error.into_kind())),
+        Err((2, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_escaped_representation(r#""\x100""#)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((1, Error::InvalidEncoding))
+    );
+    assert_eq!(
+        parse_escaped_representation(r#""\400""#)
+            .map_err(|error| (error.offset(), error.into_kind())),
+        Err((1, Error::InvalidEncoding))
+    );
+}
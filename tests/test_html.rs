@@ -0,0 +1,109 @@
+#![cfg(all(feature = "repr-html", feature = "repr-dump", feature = "repr-escaped"))]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::{
+        dump::DumpFormatOptions,
+        escaped::EscapedFormatOptions,
+        html::{html_representation, HtmlFormatOptions, STYLESHEET},
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_html_escaped() {
+    let binary = Binary::from(b"<a href='x'>&\x00");
+    assert_eq!(
+        html_representation(
+            &binary,
+            &HtmlFormatOptions::new(EscapedFormatOptions::default())
+        ),
+        concat!(
+            r#"<pre class="wrapbin"><span class="wrapbin-prefix">b</span>"#,
+            r#"<span class="wrapbin-delimiter">&quot;</span>"#,
+            r#"<span class="wrapbin-printable">&lt;</span>"#,
+            r#"<span class="wrapbin-printable">a</span>"#,
+            r#"<span class="wrapbin-control"> </span>"#,
+            r#"<span class="wrapbin-printable">h</span>"#,
+            r#"<span class="wrapbin-printable">r</span>"#,
+            r#"<span class="wrapbin-printable">e</span>"#,
+            r#"<span class="wrapbin-printable">f</span>"#,
+            r#"<span class="wrapbin-printable">=</span>"#,
+            r#"<span class="wrapbin-printable">&#39;</span>"#,
+            r#"<span class="wrapbin-printable">x</span>"#,
+            r#"<span class="wrapbin-printable">&#39;</span>"#,
+            r#"<span class="wrapbin-printable">&gt;</span>"#,
+            r#"<span class="wrapbin-printable">&amp;</span>"#,
+            r#"<span class="wrapbin-control">\0</span>"#,
+            r#"<span class="wrapbin-delimiter">&quot;</span></pre>"#,
+        )
+    );
+}
+
+#[test]
+fn test_html_dump() {
+    let binary = Binary::from(b"<tag>");
+    let options = DumpFormatOptions::canonical_hex_dump()
+        .with_bytes_per_line(8)
+        .annotate(1..4, "name & <value>");
+    let html = html_representation(&binary, &HtmlFormatOptions::new(options));
+    assert_eq!(
+        html,
+        concat!(
+            r#"<pre class="wrapbin"><span class="wrapbin-index">000000:  </span>"#,
+            r#"<span class="wrapbin-printable">3c</span> "#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">74</span> "#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">61</span> "#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">67</span> "#,
+            r#"<span class="wrapbin-printable">3e</span> "#,
+            r#"          <span class="wrapbin-delimiter">|</span>"#,
+            r#"<span class="wrapbin-printable">&lt;</span>"#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">t</span>"#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">a</span>"#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">g</span>"#,
+            r#"<span class="wrapbin-printable">&gt;</span>"#,
+            r#"<span class="wrapbin-delimiter">|</span> "#,
+            r#"<span class="wrapbin-delimiter"> ◂ </span>"#,
+            r#"<span class="wrapbin-printable wrapbin-annotation-0">name &amp; &lt;value&gt;</span>"#,
+            r#"</pre>"#,
+        )
+    );
+}
+
+#[test]
+fn test_html_stylesheet() {
+    let binary = Binary::from(b"AB");
+    let html = html_representation(
+        &binary,
+        &HtmlFormatOptions::new(DumpFormatOptions::default()).with_stylesheet(true),
+    );
+    assert!(html.starts_with(&format!(
+        "<style>\n{STYLESHEET}</style>\n<pre class=\"wrapbin\">"
+    )));
+    assert!(html.ends_with("</pre>"));
+    assert!(!html.contains('\u{1b}'));
+}
+
+#[test]
+fn test_html_dump_hostile_label() {
+    let binary = Binary::from(b"<tag>");
+    let options = DumpFormatOptions::canonical_hex_dump()
+        .with_bytes_per_line(8)
+        .annotate(0..2, "x\u{1b}]evil\" onmouseover=\"alert(1)\u{1b}\\y");
+    let html = html_representation(&binary, &HtmlFormatOptions::new(options));
+    assert!(html.contains(concat!(
+        r#"<span class="wrapbin-printable wrapbin-annotation-0">"#,
+        "x\u{241b}]evil&quot; onmouseover=&quot;alert(1)\u{241b}\\y</span>"
+    )));
+    assert!(!html.contains("onmouseover=\""));
+    assert!(!html.contains("class=\"evil"));
+    assert!(!html.contains('\u{1b}'));
+    assert_eq!(
+        html.matches("<span").count(),
+        html.matches("</span>").count()
+    );
+}
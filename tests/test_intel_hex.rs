@@ -0,0 +1,207 @@
+#![cfg(feature = "repr-intel-hex")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::Error,
+    repr::intel_hex::{
+        intel_hex_representation, parse_intel_hex_image, parse_intel_hex_representation,
+        try_intel_hex_representation, write_intel_hex_representation, IntelHexAddressMode,
+        IntelHexFormatOptions, IntelHexRecordLength, IntelHexStartAddress,
+    },
+    repr::{format, try_format},
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+#[test]
+fn test_intel_hex_representation() {
+    let binary = Binary::from("Hello World!");
+    let repr = intel_hex_representation(&binary, &IntelHexFormatOptions::default());
+    assert_eq!(repr, ":0C00000048656C6C6F20576F726C6421B7\n:00000001FF\n");
+    assert_eq!(parse_intel_hex_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_intel_hex_record_length() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let options =
+        IntelHexFormatOptions::default().with_record_length(IntelHexRecordLength::ThirtyTwo);
+    let repr = intel_hex_representation(&binary, &options);
+    assert!(repr.lines().next().unwrap().starts_with(":20000000"));
+    assert_eq!(
+        repr.lines().count(),
+        LOREM_IPSUM_TEXT.len().div_ceil(32) + 1
+    );
+    assert_eq!(parse_intel_hex_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_intel_hex_extended_linear_address() {
+    let binary = Binary::from([0x01_u8, 0x02, 0x03, 0x04]);
+    let options = IntelHexFormatOptions::default()
+        .with_base_address(0x0001_FFFE)
+        .with_start_address(Some(IntelHexStartAddress::Linear(0x0001_FFFE)));
+    let repr = intel_hex_representation(&binary, &options);
+    assert_eq!(
+        repr,
+        [
+            ":020000040001F9",
+            ":02FFFE000102FE",
+            ":020000040002F8",
+            ":020000000304F7",
+            ":040000050001FFFEF9",
+            ":00000001FF",
+            "",
+        ]
+        .join("\n")
+    );
+    let image = parse_intel_hex_image(&repr).unwrap();
+    assert_eq!(image.base_address(), 0x0001_FFFE);
+    assert_eq!(
+        image.start_address(),
+        Some(IntelHexStartAddress::Linear(0x0001_FFFE))
+    );
+    assert_eq!(image.binary(), &binary);
+}
+
+#[test]
+fn test_intel_hex_extended_segment_address() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let start_address = IntelHexStartAddress::Segment {
+        code_segment: 0x1000,
+        instruction_pointer: 0x0100,
+    };
+    let options = IntelHexFormatOptions::default()
+        .with_address_mode(IntelHexAddressMode::Segment)
+        .with_base_address(0x0001_0100)
+        .with_start_address(Some(start_address));
+    let repr = intel_hex_representation(&binary, &options);
+    assert!(repr.starts_with(":020000021000EC\n:10010000"));
+    assert!(repr.ends_with(":0400000310000100E8\n:00000001FF\n"));
+    let image = parse_intel_hex_image(&repr).unwrap();
+    assert_eq!(image.base_address(), 0x0001_0100);
+    assert_eq!(image.start_address(), Some(start_address));
+    assert_eq!(image.into_binary(), binary);
+}
+
+#[test]
+fn test_intel_hex_parse_gaps() {
+    let image = parse_intel_hex_image(":020010000102EB\n\n:020014000304E3\n:00000001FF\n").unwrap();
+    assert_eq!(image.base_address(), 0x0010);
+    assert_eq!(image.start_address(), None);
+    assert_eq!(
+        image.binary().as_ref(),
+        &[0x01, 0x02, 0xFF, 0xFF, 0x03, 0x04]
+    );
+}
+
+#[test]
+fn test_intel_hex_address_range() {
+    let binary = Binary::from("Hello World!");
+    let options = IntelHexFormatOptions::default()
+        .with_address_mode(IntelHexAddressMode::Segment)
+        .with_base_address(0x0012_0000);
+    assert_eq!(
+        try_intel_hex_representation(&binary, &options),
+        Err(Error::AddressOutOfRange {
+            address: 0x0012_000B,
+            limit: 0x000F_FFFF
+        })
+    );
+    let mut buffer = String::new();
+    assert!(write_intel_hex_representation(&mut buffer, &binary, &options).is_err());
+    assert_eq!(buffer, "");
+
+    let options = IntelHexFormatOptions::default().with_base_address(0xFFFF_FFF8);
+    assert_eq!(
+        try_intel_hex_representation(&binary, &options),
+        Err(Error::AddressOutOfRange {
+            address: 0x1_0000_0003,
+            limit: 0xFFFF_FFFF
+        })
+    );
+    let options = IntelHexFormatOptions::default().with_base_address(0xFFFF_FFF4);
+    let repr = try_intel_hex_representation(&binary, &options).unwrap();
+    let image = parse_intel_hex_image(&repr).unwrap();
+    assert_eq!(image.base_address(), 0xFFFF_FFF4);
+    assert_eq!(image.into_binary(), binary);
+}
+
+#[test]
+fn test_intel_hex_try_format() {
+    let binary = Binary::from(b"Hello World!");
+    let options = IntelHexFormatOptions::default().with_base_address(0xFFFF_FFF8);
+    assert_eq!(
+        try_format(&binary, options),
+        Err(Error::AddressOutOfRange {
+            address: 0x1_0000_0003,
+            limit: 0xFFFF_FFFF
+        })
+    );
+    let options = options.with_base_address(0xFFFF_FFF4);
+    assert_eq!(
+        try_format(&binary, options),
+        Ok(intel_hex_representation(&binary, &options))
+    );
+}
+
+#[test]
+#[should_panic(expected = "the value cannot be written in the representation with these options")]
+fn test_intel_hex_format_out_of_range() {
+    let binary = Binary::from(b"Hello World!");
+    let _ = format(
+        &binary,
+        IntelHexFormatOptions::default().with_base_address(0xFFFF_FFF8),
+    );
+}
+
+#[test]
+fn test_intel_hex_parse_sparse() {
+    let error =
+        parse_intel_hex_image(":0100000000FF\n:02000004FFFFFC\n:01FFF0000010\n:00000001FF\n")
+            .unwrap_err();
+    assert_eq!(error.line(), 3);
+    assert_eq!(
+        error.into_kind(),
+        Error::AddressGapTooLarge { limit: 0x100_0000 }
+    );
+}
+
+#[test]
+fn test_intel_hex_parse_errors() {
+    assert_eq!(
+        parse_intel_hex_representation(":0C00000048656C6C6F20576F726C6421B8\n:00000001FF\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((1, Error::RecordChecksumMismatch))
+    );
+    assert_eq!(
+        parse_intel_hex_representation(":020010000102EB\n0200140003040F\n:00000001FF\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_intel_hex_representation(":0300100001020A\n:00000001FF\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((1, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_intel_hex_representation(":020010000102EB\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_intel_hex_representation(":00000001FF\n:020010000102EB\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_intel_hex_representation(":020010060102E5\n:00000001FF\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((1, Error::InvalidRecord))
+    );
+}
@@ -0,0 +1,76 @@
+#![cfg(all(feature = "repr-markdown", feature = "repr-escaped"))]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::{
+        dump::DumpFormatOptions,
+        escaped::EscapedFormatOptions,
+        markdown::{markdown_dump_table, markdown_representation},
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_markdown_fenced() {
+    assert_eq!(
+        markdown_representation(&Binary::from(b"a``b"), EscapedFormatOptions::default()),
+        "```text\nb\"a``b\"\n```"
+    );
+    assert_eq!(
+        markdown_representation(&Binary::from(b"```"), EscapedFormatOptions::default()),
+        "````text\nb\"```\"\n````"
+    );
+}
+
+#[test]
+fn test_markdown_dump_table() {
+    let binary = Binary::from(
+        [0_u8; 8]
+            .iter()
+            .copied()
+            .chain(*b"|*_|")
+            .collect::<Vec<_>>(),
+    );
+    let options = DumpFormatOptions::default()
+        .with_lower_hex_bytes()
+        .with_lower_hex_indices()
+        .with_bytes_per_line(2)
+        .squeeze(true)
+        .show_ascii_gutter(true)
+        .annotate(9..10, "flags");
+    assert_eq!(
+        markdown_dump_table(&binary, &options),
+        [
+            "| Offset | 00 | 01 | Text | Labels |",
+            "| --- | --- | --- | --- | --- |",
+            "| 000000 | 00 | 00 | .. |  |",
+            "| \\* |  |  |  |  |",
+            "| 000008 | 7c | 2a | \\|\\* | flags |",
+            "| 00000a | 5f | 7c | \\_\\| |  |",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_markdown_dump_table_offset() {
+    let binary = Binary::from(b"Hello");
+    let options = DumpFormatOptions::default()
+        .with_bytes_per_line(4)
+        .with_offset(1)
+        .show_ascii(true);
+    assert_eq!(
+        markdown_dump_table(&binary, &options),
+        [
+            "| Offset | 00 | 01 | 02 | 03 |",
+            "| --- | --- | --- | --- | --- |",
+            "| 000000 |  | e | l | l |",
+            "| 000004 | o |  |  |  |",
+        ]
+        .join("\n")
+    );
+}
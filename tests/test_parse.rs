@@ -0,0 +1,224 @@
+#![cfg(all(
+    feature = "repr-array",
+    feature = "repr-base64",
+    feature = "repr-dump",
+    feature = "repr-string"
+))]
+
+use pretty_assertions::assert_eq;
+use std::str::FromStr;
+use wrapbin::{
+    error::{Error, ParseError},
+    repr::{
+        array::ArrayFormatOptions, base64::Base64FormatOptions, detect_representation,
+        dump::DumpFormatOptions, format, parse, string::StringFormatOptions, BinaryFormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+const LOREM_IPSUM_A: &str = include_str!("lorem_ipsum_a.txt");
+const LOREM_IPSUM_B: &str = include_str!("lorem_ipsum_b.txt");
+const LOREM_IPSUM_S: &str = include_str!("lorem_ipsum_s.txt");
+
+#[test]
+fn test_detect_array() {
+    assert_eq!(
+        detect_representation("0x[01, 0e, b2, 8c]"),
+        Some(ArrayFormatOptions::default().with_lower_hex_bytes().into())
+    );
+    assert_eq!(
+        detect_representation("0b[1,1110,10110010,10001100]"),
+        Some(
+            ArrayFormatOptions::default()
+                .with_binary_bytes()
+                .compact(true)
+                .into()
+        )
+    );
+}
+
+#[test]
+fn test_detect_string() {
+    assert_eq!(
+        detect_representation(r#"0X"01_0E_B2_8C""#),
+        Some(StringFormatOptions::default().into())
+    );
+    assert_eq!(
+        detect_representation(r#"0o"001016262214""#),
+        Some(
+            StringFormatOptions::default()
+                .with_octal_bytes()
+                .compact(true)
+                .into()
+        )
+    );
+}
+
+#[test]
+fn test_detect_base64() {
+    assert_eq!(
+        detect_representation("AQ6yjA=="),
+        Some(Base64FormatOptions::default().into())
+    );
+    assert_eq!(
+        detect_representation("AQ6y+A"),
+        Some(Base64FormatOptions::default().compact(true).into())
+    );
+}
+
+#[test]
+fn test_detect_base64_ambiguous() {
+    for s in ["deadbeef", "1234", "hello", "AQ6yjA", ""] {
+        assert_eq!(detect_representation(s), None, "detected {s:?}");
+        assert_eq!(
+            Binary::from_str(s).map_err(ParseError::into_kind),
+            Err(Error::InvalidRepresentation)
+        );
+    }
+    assert_eq!(
+        detect_representation("AQ6yjA=="),
+        Some(Base64FormatOptions::default().into())
+    );
+}
+
+#[test]
+fn test_detect_base64_variants() {
+    assert_eq!(
+        detect_representation("-_-_AQ"),
+        Some(
+            Base64FormatOptions::default()
+                .with_url_safe_alphabet()
+                .compact(true)
+                .into()
+        )
+    );
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in [Base64FormatOptions::pem(), Base64FormatOptions::mime()] {
+        let repr = format(&binary, options);
+        assert_eq!(detect_representation(&repr), Some(options.into()));
+        assert_eq!(parse(&repr), Ok(binary.clone()));
+    }
+}
+
+#[cfg(feature = "repr-escaped")]
+#[test]
+fn test_detect_escaped() {
+    use wrapbin::repr::escaped::EscapedFormatOptions;
+    assert_eq!(
+        detect_representation(r#"b"GET /\r\n""#),
+        Some(EscapedFormatOptions::default().with_rust_dialect().into())
+    );
+    assert_eq!(
+        detect_representation(r#""GET /\r\n""#),
+        Some(EscapedFormatOptions::default().with_c_dialect().into())
+    );
+    assert_eq!(parse(r#" "GET /\r\n" "#), Ok(Binary::from("GET /\r\n")));
+}
+
+#[cfg(feature = "repr-ascii85")]
+#[test]
+fn test_detect_ascii85() {
+    use wrapbin::repr::ascii85::Ascii85FormatOptions;
+    assert_eq!(
+        detect_representation(" <~9jqo^F*2M7/c~>\n"),
+        Some(Ascii85FormatOptions::adobe().into())
+    );
+    assert_eq!(parse("<~9jqo^F*2M7/c~>"), Ok(Binary::from("Man sure.")));
+}
+
+#[cfg(feature = "repr-intel-hex")]
+#[test]
+fn test_detect_intel_hex() {
+    use wrapbin::repr::intel_hex::{IntelHexFormatOptions, IntelHexRecordLength};
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let options =
+        IntelHexFormatOptions::default().with_record_length(IntelHexRecordLength::ThirtyTwo);
+    let repr = format(&binary, options);
+    assert_eq!(detect_representation(&repr), Some(options.into()));
+    assert_eq!(parse(&repr), Ok(binary));
+}
+
+#[cfg(feature = "repr-srec")]
+#[test]
+fn test_detect_srec() {
+    use wrapbin::repr::srec::{SRecordAddressWidth, SRecordFormatOptions};
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let options = SRecordFormatOptions::default().with_address_width(SRecordAddressWidth::Bits24);
+    let repr = format(&binary, options.clone());
+    assert_eq!(detect_representation(&repr), Some(options.into()));
+    assert_eq!(parse(&repr), Ok(binary));
+}
+
+#[test]
+fn test_detect_dump() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in [
+        DumpFormatOptions::classic_hex_dump(),
+        DumpFormatOptions::octal_dump(),
+        DumpFormatOptions::binary_dump(),
+        DumpFormatOptions::lower_hex_dump(),
+        DumpFormatOptions::canonical_hex_dump(),
+    ] {
+        let repr = format(&binary, options);
+        assert!(matches!(
+            detect_representation(&repr),
+            Some(BinaryFormatOptions::Dump(_))
+        ));
+    }
+}
+
+#[test]
+fn test_detect_nothing() {
+    assert_eq!(detect_representation("0c[01, 02]"), None);
+    assert_eq!(detect_representation("hello, world"), None);
+}
+
+#[test]
+fn test_parse_all_representations() {
+    let expected = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for repr in [LOREM_IPSUM_A, LOREM_IPSUM_B, LOREM_IPSUM_S] {
+        assert_eq!(parse(repr), Ok(expected.clone()));
+    }
+}
+
+#[test]
+fn test_parse_format_round_trip() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in [
+        BinaryFormatOptions::from(ArrayFormatOptions::default().with_octal_bytes()),
+        StringFormatOptions::default().compact(true).into(),
+        Base64FormatOptions::default().into(),
+        DumpFormatOptions::decimal_dump().into(),
+    ] {
+        let repr = format(&binary, options.clone());
+        let detected = detect_representation(&repr).unwrap();
+        assert_eq!(format(&parse(&repr).unwrap(), detected), repr);
+    }
+}
+
+#[test]
+fn test_from_str() {
+    let binary = Binary::from_str("0x[01, 0e, b2, 8c]").unwrap();
+    assert!(binary.is_owned());
+    assert_eq!(binary.as_ref(), &[0x01, 0x0e, 0xb2, 0x8c]);
+
+    let binary: Binary<'static> = "  AQ6yjA==\n".parse().unwrap();
+    assert_eq!(binary.as_ref(), &[0x01, 0x0e, 0xb2, 0x8c]);
+}
+
+#[test]
+fn test_from_str_error() {
+    assert_eq!(
+        Binary::from_str("hello, world").map_err(ParseError::into_kind),
+        Err(Error::InvalidRepresentation)
+    );
+    assert_eq!(
+        Binary::from_str("AQ6yj==").map_err(|error| (error.offset(), error.into_kind())),
+        Err((5, Error::InvalidEncoding))
+    );
+}
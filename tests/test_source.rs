@@ -0,0 +1,118 @@
+#![cfg(feature = "repr-source")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::source::{source_representation, SourceFormatOptions, SourceLanguage},
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const BYTES: [u8; 5] = [0x00, 0x07, 0x48, 0x69, 0xff];
+
+#[test]
+fn test_source_languages() {
+    let binary = Binary::from(BYTES);
+    let options = SourceFormatOptions::default().with_bytes_per_line(3);
+    for (language, expected) in [
+        (
+            SourceLanguage::Rust,
+            "const DATA: [u8; 5] = [\n    0x00, 0x07, 0x48,\n    0x69, 0xff,\n];",
+        ),
+        (
+            SourceLanguage::C,
+            "unsigned char data[] = {\n    0x00, 0x07, 0x48,\n    0x69, 0xff,\n};\nunsigned int data_len = 5;",
+        ),
+        (
+            SourceLanguage::Python,
+            "data = bytes([\n    0x00, 0x07, 0x48,\n    0x69, 0xff,\n])",
+        ),
+        (
+            SourceLanguage::Go,
+            "var data = []byte{\n    0x00, 0x07, 0x48,\n    0x69, 0xff,\n}",
+        ),
+        (
+            SourceLanguage::JavaScript,
+            "const data = new Uint8Array([\n    0x00, 0x07, 0x48,\n    0x69, 0xff,\n]);",
+        ),
+    ] {
+        assert_eq!(
+            source_representation(&binary, &options.clone().with_language(language)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_source_radix_formats() {
+    let binary = Binary::from(BYTES);
+    let options = SourceFormatOptions::default()
+        .with_python_language()
+        .with_name("b")
+        .with_indent("\t");
+    assert_eq!(
+        source_representation(&binary, &options.clone().with_decimal_bytes()),
+        "b = bytes([\n\t  0,   7,  72, 105, 255,\n])"
+    );
+    assert_eq!(
+        source_representation(&binary, &options.clone().with_octal_bytes()),
+        "b = bytes([\n\t0o000, 0o007, 0o110, 0o151, 0o377,\n])"
+    );
+    assert_eq!(
+        source_representation(
+            &binary,
+            &options.clone().with_upper_hex_bytes().compact(true)
+        ),
+        "b = bytes([\n\t0x0,0x7,0x48,0x69,0xFF,\n])"
+    );
+    assert_eq!(
+        source_representation(
+            &binary,
+            &options.with_c_language().with_octal_bytes().compact(true)
+        ),
+        "unsigned char b[] = {\n\t00,07,0110,0151,0377,\n};\nunsigned int b_len = 5;"
+    );
+}
+
+#[test]
+fn test_source_binary_bytes() {
+    let binary = Binary::from([0x05_u8, 0xa0]);
+    assert_eq!(
+        source_representation(
+            &binary,
+            &SourceFormatOptions::default()
+                .with_go_language()
+                .with_binary_bytes()
+        ),
+        "var data = []byte{\n    0b00000101, 0b10100000,\n}"
+    );
+}
+
+#[test]
+fn test_source_empty() {
+    let binary = Binary::from(Vec::new());
+    assert_eq!(
+        source_representation(&binary, &SourceFormatOptions::default()),
+        "const DATA: [u8; 0] = [];"
+    );
+    assert_eq!(
+        source_representation(&binary, &SourceFormatOptions::default().with_c_language()),
+        "unsigned char data[1] = {0};\nunsigned int data_len = 0;"
+    );
+}
+
+#[test]
+fn test_source_c_binary_bytes() {
+    let binary = Binary::from([0x05_u8, 0xa0]);
+    assert_eq!(
+        source_representation(
+            &binary,
+            &SourceFormatOptions::default()
+                .with_c_language()
+                .with_binary_bytes()
+        ),
+        "unsigned char data[] = {\n    0x05, 0xa0,\n};\nunsigned int data_len = 2;"
+    );
+}
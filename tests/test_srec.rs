@@ -0,0 +1,184 @@
+#![cfg(feature = "repr-srec")]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    error::Error,
+    repr::srec::{
+        parse_srec_image, parse_srec_representation, srec_representation, try_srec_representation,
+        write_srec_representation, SRecordAddressWidth, SRecordFormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+#[test]
+fn test_srec_representation() {
+    let binary = Binary::from("Hello World!");
+    let repr = srec_representation(&binary, &SRecordFormatOptions::default());
+    assert_eq!(
+        repr,
+        [
+            "S0030000FC",
+            "S10F000048656C6C6F20576F726C6421B3",
+            "S5030001FB",
+            "S9030000FC",
+            "",
+        ]
+        .join("\n")
+    );
+    assert_eq!(parse_srec_representation(&repr), Ok(binary));
+}
+
+#[test]
+fn test_srec_automatic_address_width() {
+    let binary = Binary::from([0x01_u8, 0x02, 0x03, 0x04]);
+    let options = SRecordFormatOptions::default()
+        .with_base_address(0xFFFE)
+        .with_start_address(0xFFFE);
+    let repr = srec_representation(&binary, &options);
+    assert_eq!(
+        repr,
+        [
+            "S0030000FC",
+            "S20800FFFE01020304F0",
+            "S5030001FB",
+            "S80400FFFEFE",
+            "",
+        ]
+        .join("\n")
+    );
+    let image = parse_srec_image(&repr).unwrap();
+    assert_eq!(image.header(), b"");
+    assert_eq!(image.base_address(), 0xFFFE);
+    assert_eq!(image.start_address(), 0xFFFE);
+    assert_eq!(image.binary(), &binary);
+}
+
+#[test]
+fn test_srec_explicit_address_width() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let options = SRecordFormatOptions::default()
+        .with_header("lorem_ipsum.bin")
+        .with_address_width(SRecordAddressWidth::Bits32)
+        .with_base_address(0x0800_0000)
+        .with_start_address(0x0800_0100);
+    let repr = srec_representation(&binary, &options);
+    let lines: Vec<&str> = repr.lines().collect();
+    assert!(lines[1].starts_with("S31508000000"));
+    assert!(lines[lines.len() - 1].starts_with("S70508000100"));
+    let image = parse_srec_image(&repr).unwrap();
+    assert_eq!(image.header(), b"lorem_ipsum.bin");
+    assert_eq!(image.base_address(), 0x0800_0000);
+    assert_eq!(image.start_address(), 0x0800_0100);
+    assert_eq!(image.into_binary(), binary);
+
+    // A width too narrow for the addresses is widened.
+    let options = options.with_address_width(SRecordAddressWidth::Bits16);
+    assert!(srec_representation(&binary, &options)
+        .lines()
+        .nth(1)
+        .unwrap()
+        .starts_with("S315"));
+}
+
+#[test]
+fn test_srec_parse_gaps() {
+    let image = parse_srec_image("S307000000100102E5\n\nS10500140304DF\nS9030000FC\n").unwrap();
+    assert_eq!(image.base_address(), 0x0010);
+    assert_eq!(
+        image.binary().as_ref(),
+        &[0x01, 0x02, 0xFF, 0xFF, 0x03, 0x04]
+    );
+}
+
+#[test]
+fn test_srec_long_header() {
+    let binary = Binary::from("Hello World!");
+    let options = SRecordFormatOptions::default().with_header("A".repeat(252));
+    let image = parse_srec_image(&srec_representation(&binary, &options)).unwrap();
+    assert_eq!(image.header(), "A".repeat(252).as_bytes());
+    assert_eq!(image.into_binary(), binary);
+
+    let options = SRecordFormatOptions::default().with_header("é".repeat(127));
+    assert_eq!(
+        try_srec_representation(&binary, &options),
+        Err(Error::HeaderTooLong {
+            length: 254,
+            limit: 252
+        })
+    );
+    let mut buffer = String::new();
+    assert!(write_srec_representation(&mut buffer, &binary, &options).is_err());
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_srec_address_space_boundary() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes()[..16].to_vec());
+    let options = SRecordFormatOptions::default().with_base_address(0xFFFF_FFF0);
+    let image = parse_srec_image(&try_srec_representation(&binary, &options).unwrap()).unwrap();
+    assert_eq!(image.base_address(), 0xFFFF_FFF0);
+    assert_eq!(image.into_binary(), binary);
+
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes()[..32].to_vec());
+    assert_eq!(
+        try_srec_representation(&binary, &options),
+        Err(Error::AddressOutOfRange {
+            address: 0x1_0000_000F,
+            limit: 0xFFFF_FFFF
+        })
+    );
+    let mut buffer = String::new();
+    assert!(write_srec_representation(&mut buffer, &binary, &options).is_err());
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_srec_parse_sparse() {
+    let error =
+        parse_srec_image("S3060000000000F9\nS306FFFFFFF0000C\nS70500000000FA\n").unwrap_err();
+    assert_eq!(error.line(), 2);
+    assert_eq!(
+        error.into_kind(),
+        Error::AddressGapTooLarge { limit: 0x100_0000 }
+    );
+}
+
+#[test]
+fn test_srec_parse_errors() {
+    assert_eq!(
+        parse_srec_representation("S0030000FC\nS10F000048656C6C6F20576F726C6421B4\nS9030000FC\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::RecordChecksumMismatch))
+    );
+    assert_eq!(
+        parse_srec_representation("S0030000FC\n:0200100001020B\nS9030000FC\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_srec_representation("S10F000048656C6C6F20576F726C6421B3\nS5030002FA\nS9030000FC\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_srec_representation("S10F000048656C6C6F20576F726C6421B3\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_srec_representation("S9030000FC\nS10F000048656C6C6F20576F726C6421B3\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((2, Error::InvalidRecord))
+    );
+    assert_eq!(
+        parse_srec_representation("S40F000048656C6C6F20576F726C6421B3\nS9030000FC\n")
+            .map_err(|error| (error.line(), error.into_kind())),
+        Err((1, Error::InvalidRecord))
+    );
+}
@@ -3,7 +3,7 @@
 use core::num::IntErrorKind;
 use pretty_assertions::assert_eq;
 use wrapbin::{
-    error::Error,
+    error::{Error, ParseError},
     repr::string::{parse_string_representation, string_representation, StringFormatOptions},
     Binary,
 };
@@ -22,37 +22,37 @@ const TEST_ARRAY: [u8; 32] = [
 
 #[test]
 fn test_parse_error_missing_radix_prefix() {
-    let result = parse_string_representation("\"\"");
+    let result = parse_string_representation("\"\"").map_err(ParseError::into_kind);
     assert_eq!(result, Err(Error::MissingRadixPrefix));
 }
 
 #[test]
 fn test_parse_error_invalid_radix_prefix() {
-    let result = parse_string_representation("0\"\"");
+    let result = parse_string_representation("0\"\"").map_err(ParseError::into_kind);
     assert_eq!(result, Err(Error::InvalidRadixPrefix));
 }
 
 #[test]
 fn test_parse_error_invalid_radix_prefix_2() {
-    let result = parse_string_representation("0c\"\"");
+    let result = parse_string_representation("0c\"\"").map_err(ParseError::into_kind);
     assert_eq!(result, Err(Error::InvalidRadixPrefix));
 }
 
 #[test]
 fn test_parse_error_invalid_string_quotes_1() {
-    let result = parse_string_representation("0x00_ff\"");
+    let result = parse_string_representation("0x00_ff\"").map_err(ParseError::into_kind);
     assert_eq!(result, Err(Error::InvalidStringQuotes));
 }
 
 #[test]
 fn test_parse_error_invalid_string_quotes_2() {
-    let result = parse_string_representation("0x\"00_ff");
+    let result = parse_string_representation("0x\"00_ff").map_err(ParseError::into_kind);
     assert_eq!(result, Err(Error::InvalidStringQuotes));
 }
 
 #[test]
 fn test_parse_error_invalid_byte_representation_1() {
-    let result = parse_string_representation("0x\"0x\"");
+    let result = parse_string_representation("0x\"0x\"").map_err(ParseError::into_kind);
     if let Err(Error::InvalidByteRepresentation { source }) = result {
         assert_eq!(source.kind(), &IntErrorKind::InvalidDigit);
     } else {
@@ -62,13 +62,13 @@ fn test_parse_error_invalid_byte_representation_1() {
 
 #[test]
 fn test_parse_error_invalid_byte_representation_2() {
-    let result = parse_string_representation("0x\"1ff\"");
+    let result = parse_string_representation("0x\"1ff\"").map_err(ParseError::into_kind);
     assert_eq!(result, Err(Error::InvalidRepresentation));
 }
 
 #[test]
 fn test_parse_error_invalid_byte_representation_3() {
-    let result = parse_string_representation("0x\"0 ff\"");
+    let result = parse_string_representation("0x\"0 ff\"").map_err(ParseError::into_kind);
     if let Err(Error::InvalidByteRepresentation { source }) = result {
         assert_eq!(source.kind(), &IntErrorKind::InvalidDigit);
     } else {
@@ -0,0 +1,181 @@
+#![cfg(all(
+    feature = "repr-array",
+    feature = "repr-dump",
+    feature = "repr-string",
+    feature = "repr-color"
+))]
+
+use anstyle::{Ansi256Color, AnsiColor, Color, RgbColor, Style};
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::{
+        array::{array_representation, ArrayFormatOptions},
+        dump::{dump_representation, parse_dump_representation, DumpFormatOptions},
+        string::{string_representation, StringFormatOptions},
+        ByteCategory, ByteKind, ByteStyling, ColorDepth, ReprComponentKind, Theme,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+#[test]
+fn test_theme_styles() {
+    let red = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Red)));
+    let theme = Theme::default()
+        .with_component_style(ReprComponentKind::Index, red)
+        .with_byte_kind_style(ByteKind::Undefined, red.bold());
+    assert_eq!(theme.component_style(ReprComponentKind::Index), red);
+    assert_eq!(
+        theme.component_style(ReprComponentKind::Value(ByteKind::Undefined)),
+        red.bold()
+    );
+    assert_eq!(
+        theme.byte_kind_style(ByteKind::Printable),
+        Theme::default().byte_kind_style(ByteKind::Printable)
+    );
+    assert_ne!(Theme::light(), Theme::default());
+}
+
+#[test]
+fn test_theme_array_and_string() {
+    let binary = Binary::from([0x41_u8, 0x00]);
+    assert_eq!(
+        array_representation(
+            &binary,
+            &ArrayFormatOptions::default()
+                .use_color(true)
+                .with_theme(Theme::monochrome_bold())
+        ),
+        "0X\u{1b}[2m[\u{1b}[0m\u{1b}[1m41\u{1b}[0m\u{1b}[2m,\u{1b}[0m \u{1b}[4m00\u{1b}[0m\u{1b}[2m]\u{1b}[0m"
+    );
+    assert_eq!(
+        string_representation(
+            &binary,
+            &StringFormatOptions::default()
+                .use_color(true)
+                .with_theme(Theme::monochrome_bold())
+        ),
+        "0X\u{1b}[2m\"\u{1b}[0m\u{1b}[1m41\u{1b}[0m\u{1b}[2m_\u{1b}[0m\u{1b}[4m00\u{1b}[0m\u{1b}[2m\"\u{1b}[0m"
+    );
+}
+
+#[test]
+fn test_theme_dump() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for theme in [
+        Theme::default(),
+        Theme::light(),
+        Theme::high_contrast(),
+        Theme::monochrome_bold(),
+    ] {
+        let repr = dump_representation(
+            &binary,
+            &DumpFormatOptions::default()
+                .use_color(true)
+                .with_theme(theme),
+        );
+        println!("{repr}");
+        assert_eq!(parse_dump_representation(&repr), Ok(binary.clone()));
+    }
+}
+
+#[test]
+fn test_byte_categories() {
+    assert_eq!(
+        [0x00_u8, b' ', b'\t', b'A', 0x1b, 0x7f, 0x80, 0xfe, 0xff].map(ByteCategory::from_byte),
+        [
+            ByteCategory::Null,
+            ByteCategory::Whitespace,
+            ByteCategory::Whitespace,
+            ByteCategory::Printable,
+            ByteCategory::OtherAscii,
+            ByteCategory::OtherAscii,
+            ByteCategory::NonAscii,
+            ByteCategory::NonAscii,
+            ByteCategory::Full,
+        ]
+    );
+}
+
+#[test]
+fn test_byte_styling_styles() {
+    let rgb = |r, g, b| Style::new().fg_color(Some(Color::Rgb(RgbColor(r, g, b))));
+    let ansi256 = |index| Style::new().fg_color(Some(Color::Ansi256(Ansi256Color(index))));
+
+    let theme = Theme::default();
+    assert_eq!(theme.byte_styling(), ByteStyling::Kind);
+    assert_eq!(
+        theme.byte_style(b'A'),
+        theme.byte_kind_style(ByteKind::Printable)
+    );
+
+    let theme = theme.with_byte_styling(ByteStyling::Gradient(ColorDepth::TrueColor));
+    assert_eq!(theme.byte_style(0x00), rgb(68, 1, 84));
+    assert_eq!(theme.byte_style(0xff), rgb(253, 231, 37));
+    assert_ne!(theme.byte_style(0x40), theme.byte_style(0x41));
+
+    let theme = theme.with_byte_styling(ByteStyling::Gradient(ColorDepth::Ansi256));
+    assert_eq!(theme.byte_style(0x00), ansi256(53));
+    assert_eq!(theme.byte_style(0xff), ansi256(220));
+
+    let theme = theme.with_byte_styling(ByteStyling::Category(ColorDepth::TrueColor));
+    assert_eq!(theme.byte_style(0x00), rgb(135, 135, 135));
+    assert_eq!(theme.byte_style(b'A'), theme.byte_style(b'z'));
+
+    let theme = theme.with_byte_styling(ByteStyling::Category(ColorDepth::Ansi256));
+    assert_eq!(theme.byte_style(0x00), ansi256(102));
+    assert_eq!(theme.byte_style(0xff), ansi256(231));
+}
+
+#[test]
+fn test_byte_styling_array_and_string() {
+    let binary = Binary::from([0x00_u8, 0xff]);
+    let theme =
+        Theme::monochrome_bold().with_byte_styling(ByteStyling::Gradient(ColorDepth::TrueColor));
+    assert_eq!(
+        array_representation(
+            &binary,
+            &ArrayFormatOptions::default().use_color(true).with_theme(theme)
+        ),
+        "0X\u{1b}[2m[\u{1b}[0m\u{1b}[38;2;68;1;84m00\u{1b}[0m\u{1b}[2m,\u{1b}[0m \u{1b}[38;2;253;231;37mFF\u{1b}[0m\u{1b}[2m]\u{1b}[0m"
+    );
+    let theme =
+        Theme::monochrome_bold().with_byte_styling(ByteStyling::Category(ColorDepth::Ansi256));
+    assert_eq!(
+        string_representation(
+            &binary,
+            &StringFormatOptions::default().use_color(true).with_theme(theme)
+        ),
+        "0X\u{1b}[2m\"\u{1b}[0m\u{1b}[38;5;102m00\u{1b}[0m\u{1b}[2m_\u{1b}[0m\u{1b}[38;5;231mFF\u{1b}[0m\u{1b}[2m\"\u{1b}[0m"
+    );
+}
+
+#[test]
+fn test_byte_styling_dump() {
+    let binary = Binary::from(
+        (0_u8..=255)
+            .chain(LOREM_IPSUM_TEXT.bytes())
+            .collect::<Vec<_>>(),
+    );
+    for byte_styling in [
+        ByteStyling::Gradient(ColorDepth::Ansi256),
+        ByteStyling::Gradient(ColorDepth::TrueColor),
+        ByteStyling::Category(ColorDepth::Ansi256),
+        ByteStyling::Category(ColorDepth::TrueColor),
+    ] {
+        let repr = dump_representation(
+            &binary,
+            &DumpFormatOptions::default()
+                .use_color(true)
+                .show_ascii_gutter(true)
+                .with_theme(Theme::default().with_byte_styling(byte_styling)),
+        );
+        println!("{repr}");
+        assert_eq!(parse_dump_representation(&repr), Ok(binary.clone()));
+    }
+}
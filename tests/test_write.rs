@@ -0,0 +1,117 @@
+#![cfg(all(
+    feature = "repr-array",
+    feature = "repr-base64",
+    feature = "repr-dump",
+    feature = "repr-escaped",
+    feature = "repr-string"
+))]
+
+use pretty_assertions::assert_eq;
+use wrapbin::{
+    repr::{
+        array::ArrayFormatOptions, base64::Base64FormatOptions, dump::DumpFormatOptions,
+        escaped::EscapedFormatOptions, format, parse, string::StringFormatOptions, write,
+        BinaryFormatOptions,
+    },
+    Binary,
+};
+
+// ------------------------------------------------------------------------------------------------
+// Integration Tests
+// ------------------------------------------------------------------------------------------------
+
+const LOREM_IPSUM_TEXT: &str = include_str!("lorem_ipsum_text.txt");
+
+fn all_options() -> Vec<BinaryFormatOptions> {
+    vec![
+        ArrayFormatOptions::default().into(),
+        ArrayFormatOptions::default()
+            .with_binary_bytes()
+            .compact(true)
+            .into(),
+        StringFormatOptions::default().with_decimal_bytes().into(),
+        EscapedFormatOptions::default().with_c_dialect().into(),
+        Base64FormatOptions::pem().into(),
+        Base64FormatOptions::default().compact(true).into(),
+        DumpFormatOptions::classic_hex_dump().into(),
+        DumpFormatOptions::octal_dump().into(),
+    ]
+}
+
+#[test]
+fn test_write_matches_format() {
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in all_options() {
+        let mut buffer = String::from(">");
+        write(&mut buffer, &binary, options.clone()).unwrap();
+        assert_eq!(buffer, format!(">{}", format(&binary, options)));
+    }
+}
+
+#[test]
+fn test_write_base64_lines() {
+    // longer than the chunks the encoder works in, so that lines span chunks.
+    let binary = Binary::from((0..2000).map(|i| (i * 7) as u8).collect::<Vec<u8>>());
+    let mut buffer = String::new();
+    write(&mut buffer, &binary, Base64FormatOptions::pem()).unwrap();
+    let lines = buffer.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 42);
+    assert!(lines[..41].iter().all(|line| line.len() == 64));
+    assert_eq!(parse(&buffer), Ok(binary));
+}
+
+#[test]
+fn test_display_streams() {
+    let binary = Binary::from(&[0x01, 0x0e, 0xb2]);
+    assert_eq!(format!("{binary}"), "0d[001, 014, 178]");
+    assert_eq!(format!("{binary:#}"), "0d[1,14,178]");
+    assert_eq!(format!("{binary:x}"), "0x[01, 0e, b2]");
+    assert_eq!(format!("{binary:#X}"), "0X[1,E,B2]");
+    assert_eq!(format!("{binary:b}"), "0b[00000001, 00001110, 10110010]");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_io_matches_format() {
+    use wrapbin::repr::write_io;
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    for options in all_options() {
+        let mut buffer: Vec<u8> = Vec::new();
+        write_io(&mut buffer, &binary, options.clone()).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), format(&binary, options));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_io_error() {
+    use std::io;
+    use wrapbin::repr::write_io;
+
+    struct Full(usize);
+
+    impl io::Write for Full {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.0 < buf.len() {
+                return Err(io::Error::new(io::ErrorKind::WriteZero, "full"));
+            }
+            self.0 -= buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let binary = Binary::from(LOREM_IPSUM_TEXT.as_bytes());
+    let error = write_io(&mut Full(100), &binary, ArrayFormatOptions::default()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(error.to_string(), "full");
+    assert!(write_io(
+        &mut Full(usize::MAX),
+        &binary,
+        ArrayFormatOptions::default()
+    )
+    .is_ok());
+}